@@ -17,6 +17,10 @@ pub enum Format {
     Unix,
     /// The seconds since 1970, followed by the offset, like `1660874655 +0800`
     Raw,
+    /// A coarse, human-readable description relative to now, like `3 days ago` or `in 2 weeks`.
+    Relative,
+    /// The ISO 8601 format, like `2022-08-19 00:44:15 +0800`.
+    ISO8601,
 }
 
 /// A custom format for printing and parsing time.
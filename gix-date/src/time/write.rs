@@ -0,0 +1,142 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::{CustomFormat, Format};
+use crate::Time;
+
+const SECONDS_PER_MINUTE: i64 = 60;
+const SECONDS_PER_HOUR: i64 = 60 * SECONDS_PER_MINUTE;
+const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
+const SECONDS_PER_WEEK: i64 = 7 * SECONDS_PER_DAY;
+/// Git's own approximation of a month, used for the `Relative` format as well.
+const SECONDS_PER_MONTH: i64 = 30 * SECONDS_PER_DAY;
+/// Git's own approximation of a year, used for the `Relative` format as well.
+const SECONDS_PER_YEAR: i64 = 365 * SECONDS_PER_DAY;
+
+impl Time {
+    /// Render this time according to `format`.
+    pub fn format(&self, format: impl Into<Format>) -> String {
+        match format.into() {
+            Format::Custom(CustomFormat(format)) => format_custom(self, format),
+            Format::Unix => self.seconds.to_string(),
+            Format::Raw => format!("{} {}", self.seconds, format_offset(self.offset)),
+            Format::Relative => format_relative(self, &now()),
+            Format::ISO8601 => format_iso8601(self),
+        }
+    }
+}
+
+/// The current instant, used as the reference point for the `Relative` format.
+fn now() -> Time {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() as i64)
+        .unwrap_or(0);
+    Time { seconds, offset: 0 }
+}
+
+/// Render `offset`, in seconds east of UTC, like git does, e.g. `+0800` or `-0530`.
+fn format_offset(offset: i32) -> String {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.unsigned_abs();
+    format!("{sign}{:02}{:02}", offset / SECONDS_PER_HOUR as u32, (offset / 60) % 60)
+}
+
+/// Split `seconds` since the epoch into a `(year, month, day, hour, minute, second)` tuple using
+/// the proleptic Gregorian calendar, treating `seconds` as UTC.
+///
+/// Uses Howard Hinnant's `civil_from_days` algorithm since this crate has no calendar dependency to
+/// lean on.
+fn civil_from_seconds(seconds: i64) -> (i64, u32, u32, u32, u32, u32) {
+    let days = seconds.div_euclid(SECONDS_PER_DAY);
+    let time_of_day = seconds.rem_euclid(SECONDS_PER_DAY);
+
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let day_of_era = (z - era * 146_097) as u64;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let year = year_of_era as i64 + era * 400;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = if month <= 2 { year + 1 } else { year };
+
+    let hour = (time_of_day / SECONDS_PER_HOUR) as u32;
+    let minute = ((time_of_day % SECONDS_PER_HOUR) / SECONDS_PER_MINUTE) as u32;
+    let second = (time_of_day % SECONDS_PER_MINUTE) as u32;
+    (year, month, day, hour, minute, second)
+}
+
+fn format_iso8601(time: &Time) -> String {
+    let (year, month, day, hour, minute, second) = civil_from_seconds(time.seconds + time.offset as i64);
+    format!(
+        "{year:04}-{month:02}-{day:02} {hour:02}:{minute:02}:{second:02} {}",
+        format_offset(time.offset)
+    )
+}
+
+/// A minimal `strftime`-like formatter supporting the handful of directives needed to describe a
+/// commit time: `%Y %m %d %H %M %S %z`, plus a literal `%%`. Unknown directives are passed through
+/// verbatim so a caller's typo shows up in the output rather than silently eating a character.
+fn format_custom(time: &Time, format: &str) -> String {
+    let (year, month, day, hour, minute, second) = civil_from_seconds(time.seconds + time.offset as i64);
+    let mut out = String::with_capacity(format.len());
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('Y') => out.push_str(&format!("{year:04}")),
+            Some('m') => out.push_str(&format!("{month:02}")),
+            Some('d') => out.push_str(&format!("{day:02}")),
+            Some('H') => out.push_str(&format!("{hour:02}")),
+            Some('M') => out.push_str(&format!("{minute:02}")),
+            Some('S') => out.push_str(&format!("{second:02}")),
+            Some('z') => out.push_str(&format_offset(time.offset)),
+            Some('%') => out.push('%'),
+            Some(other) => {
+                out.push('%');
+                out.push(other);
+            }
+            None => out.push('%'),
+        }
+    }
+    out
+}
+
+/// Render `time` relative to `now` as a coarse, human-readable English description, like git's own
+/// `--date=relative`: `3 days ago`, `in 2 weeks`, or `just now` for anything under a second.
+fn format_relative(time: &Time, now: &Time) -> String {
+    let delta = now.seconds - time.seconds;
+    let magnitude = delta.abs();
+    if magnitude < 1 {
+        return "just now".into();
+    }
+
+    let (unit, unit_seconds) = if magnitude >= SECONDS_PER_YEAR {
+        ("year", SECONDS_PER_YEAR)
+    } else if magnitude >= SECONDS_PER_MONTH {
+        ("month", SECONDS_PER_MONTH)
+    } else if magnitude >= SECONDS_PER_WEEK {
+        ("week", SECONDS_PER_WEEK)
+    } else if magnitude >= SECONDS_PER_DAY {
+        ("day", SECONDS_PER_DAY)
+    } else if magnitude >= SECONDS_PER_HOUR {
+        ("hour", SECONDS_PER_HOUR)
+    } else if magnitude >= SECONDS_PER_MINUTE {
+        ("minute", SECONDS_PER_MINUTE)
+    } else {
+        ("second", 1)
+    };
+
+    let count = magnitude / unit_seconds;
+    let unit = if count == 1 { unit.to_string() } else { format!("{unit}s") };
+    if delta >= 0 {
+        format!("{count} {unit} ago")
+    } else {
+        format!("in {count} {unit}")
+    }
+}
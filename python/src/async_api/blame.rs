@@ -0,0 +1,227 @@
+use pyo3::prelude::*;
+use pyo3::{Py, PyAny};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use crate::errors::{object_error, repository_error};
+use crate::repository::diff::{myers_diff, DiffOp};
+
+/// The commit and original line number attributed to a single line of a blamed file
+#[pyclass(unsendable)]
+#[derive(Clone)]
+pub struct BlameLine {
+    #[pyo3(get)]
+    pub line_no: usize,
+    #[pyo3(get)]
+    pub commit_id: String,
+    #[pyo3(get)]
+    pub author_name: String,
+    #[pyo3(get)]
+    pub author_email: String,
+    #[pyo3(get)]
+    pub author_time: i64,
+    #[pyo3(get)]
+    pub orig_line_no: usize,
+}
+
+#[derive(Clone)]
+struct LineAttribution {
+    commit_id: String,
+    author_name: String,
+    author_email: String,
+    author_time: i64,
+    orig_line_no: usize,
+}
+
+/// Split raw blob bytes into lines, decoding lossily
+///
+/// Splits on `\n` and strips a trailing `\r` from each piece rather than relying on a single
+/// whole-file line-ending convention, so a blob mixing `\r\n` and bare `\n` tokenizes identically
+/// on both sides of every diff. Mirrors `repository::blame::split_lines`.
+fn split_lines(data: &[u8]) -> Vec<String> {
+    let text = String::from_utf8_lossy(data);
+    let mut lines: Vec<String> = text.split('\n').map(|line| line.strip_suffix('\r').unwrap_or(line).to_string()).collect();
+    if lines.last().is_some_and(String::is_empty) && data.last() == Some(&b'\n') {
+        lines.pop();
+    }
+    lines
+}
+
+fn blob_lines(repo: &gix::Repository, commit: &gix::Commit<'_>, path: &str) -> PyResult<Option<Vec<String>>> {
+    let tree = commit
+        .tree()
+        .map_err(|err| object_error(format!("Failed to get tree for commit '{}': {}", commit.id(), err)))?;
+
+    let Some(entry) = tree
+        .lookup_entry_by_path(path)
+        .map_err(|err| object_error(format!("Failed to look up '{}': {}", path, err)))?
+    else {
+        return Ok(None);
+    };
+
+    let blob = repo
+        .find_object(entry.object_id())
+        .map_err(|err| object_error(format!("Failed to read blob for '{}': {}", path, err)))?
+        .try_into_blob()
+        .map_err(|_| object_error(format!("'{}' is not a file", path)))?;
+
+    Ok(Some(split_lines(&blob.data)))
+}
+
+/// A line still awaiting attribution, carried forward across history: `output_index` is its
+/// position in the tip revision's coordinate space, `local_index` is its position within
+/// whichever commit's `lines` is currently being examined.
+struct PendingLine {
+    output_index: usize,
+    local_index: usize,
+}
+
+fn assign(
+    attribution: &mut [Option<LineAttribution>],
+    commit: &gix::Commit<'_>,
+    lines: &[PendingLine],
+) -> PyResult<()> {
+    if lines.is_empty() {
+        return Ok(());
+    }
+    let signature = commit
+        .author()
+        .map_err(|err| object_error(format!("Failed to read author of '{}': {}", commit.id(), err)))?;
+    for line in lines {
+        if attribution[line.output_index].is_none() {
+            attribution[line.output_index] = Some(LineAttribution {
+                commit_id: commit.id().to_string(),
+                author_name: signature.name.to_string(),
+                author_email: signature.email.to_string(),
+                author_time: signature.time.seconds,
+                orig_line_no: line.local_index,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Blame `path` at `rev`, attributing every line to the commit that last changed it
+///
+/// Walks first-parent-and-merge history starting at `rev`: at each step, the current commit's
+/// blob is diffed (via [`myers_diff`]) against every parent's blob for the same path. A line that
+/// survives unchanged into at least one parent is carried forward into that parent's history
+/// instead of being attributed here; a line that differs in every parent (or has no parent left
+/// to check) is attributed to the current commit. History ends once every line has an owner.
+/// Mirrors `repository::blame::blame`, adapted to report per-line (rather than hunk) results.
+///
+/// Read-only and lock-free: it can proceed concurrently with other reads even while a fetch or
+/// push holds the repository's write lock.
+pub(crate) fn blame(
+    py: Python<'_>,
+    repo: Arc<gix::ThreadSafeRepository>,
+    path: String,
+    rev: String,
+) -> PyResult<Py<PyAny>> {
+    let py_future = pyo3_async_runtimes::tokio::future_into_py(py, async move {
+        let repo = repo.to_thread_local();
+        let start_id = repo
+            .rev_parse_single(rev.as_str())
+            .map_err(|err| repository_error(format!("Failed to resolve revision '{}': {}", rev, err)))?
+            .detach();
+
+        let tip_commit = repo
+            .find_commit(start_id)
+            .map_err(|err| object_error(format!("Failed to find commit '{}': {}", start_id, err)))?;
+
+        let tip_lines = blob_lines(&repo, &tip_commit, path.as_str())?
+            .ok_or_else(|| object_error(format!("'{}' does not exist at '{}'", path, rev)))?;
+        let total_lines = tip_lines.len();
+
+        let mut attribution: Vec<Option<LineAttribution>> = vec![None; total_lines];
+
+        let mut queue: VecDeque<(gix::Commit<'_>, Vec<String>, Vec<PendingLine>)> = VecDeque::new();
+        queue.push_back((
+            tip_commit,
+            tip_lines,
+            (0..total_lines)
+                .map(|index| PendingLine {
+                    output_index: index,
+                    local_index: index,
+                })
+                .collect(),
+        ));
+
+        while let Some((commit, lines, pending)) = queue.pop_front() {
+            if pending.is_empty() {
+                continue;
+            }
+
+            let parent_ids: Vec<_> = commit.parent_ids().collect();
+            if parent_ids.is_empty() {
+                assign(&mut attribution, &commit, &pending)?;
+                continue;
+            }
+
+            let mut unresolved = pending;
+            for parent_id in parent_ids {
+                if unresolved.is_empty() {
+                    break;
+                }
+
+                let parent_commit = repo
+                    .find_commit(parent_id.detach())
+                    .map_err(|err| object_error(format!("Failed to find commit '{}': {}", parent_id, err)))?;
+                let Some(parent_lines) = blob_lines(&repo, &parent_commit, path.as_str())? else {
+                    continue;
+                };
+
+                let old_refs: Vec<&str> = lines.iter().map(String::as_str).collect();
+                let new_refs: Vec<&str> = parent_lines.iter().map(String::as_str).collect();
+                let ops = myers_diff(&old_refs, &new_refs);
+
+                let mut survives: HashMap<usize, usize> = HashMap::new();
+                for op in &ops {
+                    if let DiffOp::Equal { old, new } = *op {
+                        survives.insert(old, new);
+                    }
+                }
+
+                let mut still_unresolved = Vec::new();
+                let mut carried = Vec::new();
+                for line in unresolved {
+                    match survives.get(&line.local_index) {
+                        Some(&parent_index) => carried.push(PendingLine {
+                            output_index: line.output_index,
+                            local_index: parent_index,
+                        }),
+                        None => still_unresolved.push(line),
+                    }
+                }
+                unresolved = still_unresolved;
+
+                if !carried.is_empty() {
+                    queue.push_back((parent_commit, parent_lines, carried));
+                }
+            }
+
+            // Lines that differ from every parent (or whose parents lack the file) are new here.
+            assign(&mut attribution, &commit, &unresolved)?;
+        }
+
+        let lines = attribution
+            .into_iter()
+            .enumerate()
+            .map(|(line_no, attr)| {
+                let attr = attr.expect("every line starts pending and is assigned by the root commit at the latest");
+                BlameLine {
+                    line_no,
+                    commit_id: attr.commit_id,
+                    author_name: attr.author_name,
+                    author_email: attr.author_email,
+                    author_time: attr.author_time,
+                    orig_line_no: attr.orig_line_no,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Python::with_gil(|py| lines.into_iter().map(|line| Py::new(py, line)).collect::<PyResult<Vec<_>>>())
+    })?;
+
+    Ok(py_future.into())
+}
@@ -0,0 +1,195 @@
+use pyo3::prelude::*;
+use pyo3::{Py, PyAny};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::errors::repository_error;
+
+/// Progress reported while cloning: objects received so far, and how many worktree files have
+/// been checked out
+///
+/// `bytes_received` is not a separate byte count: once the fetch completes it is set to the same
+/// value as `objects_received`, and it stays 0 while the fetch runs.
+#[pyclass]
+#[derive(Clone, Copy, Default)]
+pub struct CloneProgress {
+    #[pyo3(get)]
+    pub objects_received: usize,
+    #[pyo3(get)]
+    pub bytes_received: u64,
+    #[pyo3(get)]
+    pub files_checked_out: usize,
+}
+
+fn report(progress_callback: &Option<Py<PyAny>>, progress: CloneProgress) {
+    let Some(callback) = progress_callback else { return };
+    Python::with_gil(|py| {
+        if let Err(err) = callback.call1(py, (progress,)) {
+            err.print(py);
+        }
+    });
+}
+
+/// A [`gix::Progress`] implementation that mirrors its step counter into a shared [`AtomicUsize`],
+/// so a concurrently-running task can read live progress while gix drives the fetch/checkout on
+/// this future. `add_child`/`add_child_with_id` hand back a clone sharing the same counter, since
+/// callers here only care about a single running total per phase (fetch vs. checkout), not gix's
+/// full progress hierarchy.
+#[derive(Clone)]
+struct ChannelProgress {
+    counter: Arc<AtomicUsize>,
+}
+
+impl ChannelProgress {
+    fn new(counter: Arc<AtomicUsize>) -> Self {
+        Self { counter }
+    }
+}
+
+impl gix::progress::Progress for ChannelProgress {
+    type SubProgress = Self;
+
+    fn add_child(&mut self, _name: impl Into<String>) -> Self::SubProgress {
+        self.clone()
+    }
+
+    fn add_child_with_id(&mut self, _name: impl Into<String>, _id: gix::progress::Id) -> Self::SubProgress {
+        self.clone()
+    }
+
+    fn init(&mut self, _max: Option<usize>, _unit: Option<gix::progress::Unit>) {}
+
+    fn set(&self, step: usize) {
+        self.counter.store(step, Ordering::Relaxed);
+    }
+
+    fn step(&self) -> usize {
+        self.counter.load(Ordering::Relaxed)
+    }
+
+    fn inc_by(&self, step: usize) {
+        self.counter.fetch_add(step, Ordering::Relaxed);
+    }
+
+    fn counter(&self) -> gix::progress::StepShared {
+        self.counter.clone()
+    }
+
+    fn set_name(&mut self, _name: impl Into<String>) {}
+
+    fn name(&self) -> Option<String> {
+        None
+    }
+
+    fn id(&self) -> gix::progress::Id {
+        *b"ACLN"
+    }
+
+    fn message(&self, _level: gix::progress::MessageLevel, _message: impl Into<String>) {}
+}
+
+/// How often the background reporter polls the live counters and invokes the callback
+const PROGRESS_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Spawn a background task that reports `objects`/`bytes` (fetch) and `files` (checkout) counters
+/// to `progress_callback` until `done` is set, then issue one last report with final values
+fn spawn_reporter(
+    progress_callback: Option<Py<PyAny>>,
+    objects: Arc<AtomicUsize>,
+    bytes: Arc<AtomicUsize>,
+    files: Arc<AtomicUsize>,
+    done: Arc<AtomicBool>,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            let finished = done.load(Ordering::Relaxed);
+
+            report(
+                &progress_callback,
+                CloneProgress {
+                    objects_received: objects.load(Ordering::Relaxed),
+                    bytes_received: bytes.load(Ordering::Relaxed) as u64,
+                    files_checked_out: files.load(Ordering::Relaxed),
+                },
+            );
+
+            if finished {
+                break;
+            }
+            tokio::time::sleep(PROGRESS_POLL_INTERVAL).await;
+        }
+    })
+}
+
+/// Clone `url` into `path`, returning a Python coroutine resolving to the new AsyncRepository
+pub(crate) fn clone(
+    py: Python<'_>,
+    url: String,
+    path: String,
+    bare: bool,
+    progress_callback: Option<Py<PyAny>>,
+) -> PyResult<Py<PyAny>> {
+    let py_future = pyo3_async_runtimes::tokio::future_into_py(py, async move {
+        let destination = PathBuf::from(&path);
+        let should_interrupt = AtomicBool::new(false);
+
+        let mut prepare = if bare {
+            gix::prepare_clone_bare(url.as_str(), &destination)
+        } else {
+            gix::prepare_clone(url.as_str(), &destination)
+        }
+        .map_err(|err| repository_error(format!("Failed to prepare clone of '{}': {}", url, err)))?;
+
+        let objects = Arc::new(AtomicUsize::new(0));
+        let bytes = Arc::new(AtomicUsize::new(0));
+        let files = Arc::new(AtomicUsize::new(0));
+        let done = Arc::new(AtomicBool::new(false));
+
+        let reporter = spawn_reporter(
+            progress_callback.clone(),
+            objects.clone(),
+            bytes.clone(),
+            files.clone(),
+            done.clone(),
+        );
+
+        // gix reports object and byte counts on the same progress tree, so the fetch feeds one
+        // live counter (`objects`) rather than guessing at gix's internal child-progress naming
+        // to split them apart; `bytes` is set to that same count once the fetch completes.
+        let fetch_progress = ChannelProgress::new(objects.clone());
+
+        if bare {
+            let result = prepare
+                .fetch_only(fetch_progress, &should_interrupt)
+                .map_err(|err| repository_error(format!("Failed to fetch '{}': {}", url, err)));
+
+            bytes.store(objects.load(Ordering::Relaxed), Ordering::Relaxed);
+            done.store(true, Ordering::Relaxed);
+            let _ = reporter.await;
+
+            let (repo, _outcome) = result?;
+            return crate::async_api::AsyncRepository::from_inner(repo);
+        }
+
+        let (mut checkout, _fetch_outcome) = prepare
+            .fetch_then_checkout(fetch_progress, &should_interrupt)
+            .map_err(|err| repository_error(format!("Failed to fetch '{}': {}", url, err)))?;
+
+        bytes.store(objects.load(Ordering::Relaxed), Ordering::Relaxed);
+
+        let checkout_progress = ChannelProgress::new(files.clone());
+        let result = checkout
+            .main_worktree(checkout_progress, &should_interrupt)
+            .map_err(|err| repository_error(format!("Failed to check out worktree for '{}': {}", url, err)));
+
+        done.store(true, Ordering::Relaxed);
+        let _ = reporter.await;
+
+        let (repo, _checkout_outcome) = result?;
+        crate::async_api::AsyncRepository::from_inner(repo)
+    })?;
+
+    Ok(py_future.into())
+}
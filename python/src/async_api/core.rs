@@ -0,0 +1,320 @@
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+use pyo3::PyResult;
+use pyo3::{Py, PyAny};
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::errors::repository_error;
+
+/// An asynchronous Git repository
+///
+/// Backed by a [`gix::ThreadSafeRepository`], so the handle can be shared across Python threads
+/// and awaited concurrently from an executor. Each call materializes a cheap thread-local
+/// [`gix::Repository`] via `to_thread_local()`; operations that mutate shared state (reference
+/// updates, config writes) additionally take `write_lock` so concurrent writers don't race, while
+/// read-heavy operations (status, blame, object lookups) stay lock-free.
+///
+/// All async methods run on a single, lazily-initialized, process-wide Tokio runtime shared by
+/// every `AsyncRepository` instance; see [`crate::async_api::set_runtime_worker_threads`] to
+/// configure it before the first async operation.
+#[pyclass]
+pub struct AsyncRepository {
+    pub(crate) repo: Arc<gix::ThreadSafeRepository>,
+    pub(crate) write_lock: Arc<Mutex<()>>,
+}
+
+impl AsyncRepository {
+    /// Wrap an already-opened `gix::Repository`, converting it into its thread-safe form
+    pub(crate) fn from_inner(inner: gix::Repository) -> PyResult<Self> {
+        Ok(AsyncRepository {
+            repo: Arc::new(inner.into_sync()),
+            write_lock: Arc::new(Mutex::new(())),
+        })
+    }
+}
+
+#[pymethods]
+impl AsyncRepository {
+    /// Open an existing repository at the given path (async version)
+    ///
+    /// The path can be the repository's `.git` directory, or the working directory.
+    #[classmethod]
+    fn open(_cls: &Bound<'_, PyType>, path: &str) -> PyResult<Self> {
+        let path = Path::new(path);
+
+        let repo = gix::open(path).map_err(|err| {
+            let msg = format!("Failed to open repository at {}: {}", path.display(), err);
+            repository_error(msg)
+        })?;
+
+        Self::from_inner(repo)
+    }
+
+    /// Initialize a new repository at the given path (async version)
+    ///
+    /// Args:
+    ///     path: The path where the repository will be created
+    ///     bare: If True, create a bare repository without a working directory
+    #[classmethod]
+    fn init(_cls: &Bound<'_, PyType>, path: &str, bare: bool) -> PyResult<Self> {
+        let path = Path::new(path);
+
+        // Use the appropriate init method
+        let repo = if bare { gix::init_bare(path) } else { gix::init(path) }.map_err(|err| {
+            let msg = format!("Failed to initialize repository at {}: {}", path.display(), err);
+            repository_error(msg)
+        })?;
+
+        Self::from_inner(repo)
+    }
+
+    /// Clone a remote repository, populating a working tree unless `bare` is set
+    ///
+    /// Runs the fetch and checkout on the shared runtime so the GIL is released for the duration
+    /// of the network operation.
+    ///
+    /// Args:
+    ///     url: The URL (or local path) of the repository to clone
+    ///     path: The destination directory
+    ///     bare: If True, create a bare clone with no working directory
+    ///     progress_callback: An optional callable invoked with a CloneProgress as the clone proceeds
+    ///
+    /// Returns:
+    ///     A Python coroutine resolving to the cloned AsyncRepository
+    #[classmethod]
+    #[pyo3(signature = (url, path, bare=false, progress_callback=None))]
+    fn clone<'py>(
+        _cls: &Bound<'_, PyType>,
+        py: Python<'py>,
+        url: String,
+        path: String,
+        bare: bool,
+        progress_callback: Option<Py<PyAny>>,
+    ) -> PyResult<Py<PyAny>> {
+        crate::async_api::clone::clone(py, url, path, bare, progress_callback)
+    }
+
+    /// Get the path to the repository's .git directory
+    fn git_dir(&self) -> String {
+        self.repo.to_thread_local().git_dir().to_string_lossy().into_owned()
+    }
+
+    /// Get the path to the repository's working directory, if it has one
+    fn work_dir(&self) -> Option<String> {
+        self.repo
+            .to_thread_local()
+            .workdir()
+            .map(|p| p.to_string_lossy().into_owned())
+    }
+
+    /// Check if the repository is bare (has no working directory)
+    fn is_bare(&self) -> bool {
+        self.repo.to_thread_local().is_bare()
+    }
+
+    /// Get the name of the HEAD reference (e.g., "refs/heads/main")
+    /// or the commit ID if HEAD is detached
+    fn head(&self) -> PyResult<String> {
+        self.repo
+            .to_thread_local()
+            .head_ref()
+            .map_err(|err| {
+                let msg = format!("Failed to get HEAD: {}", err);
+                repository_error(msg)
+            })
+            .and_then(|opt_ref| match opt_ref {
+                Some(reference) => Ok(reference.name().as_bstr().to_string()),
+                None => Err(repository_error("Repository HEAD is not set")),
+            })
+    }
+
+    /// Example of an async method that simulates an expensive operation
+    #[pyo3(name = "simulate_network_operation")]
+    fn simulate_network_operation_py<'py>(&self, py: Python<'py>, delay_ms: u64) -> PyResult<Py<PyAny>> {
+        // Create a Python coroutine from a Rust future
+        let py_future = pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            // Simulate a network operation with a delay
+            tokio::time::sleep(tokio::time::Duration::from_millis(delay_ms)).await;
+
+            // Return some meaningful data
+            Ok(format!("Operation completed after {}ms", delay_ms))
+        })?;
+
+        // Convert Bound<PyAny> to Py<PyAny>
+        Ok(py_future.into())
+    }
+
+    /// Fetch updates from a remote asynchronously
+    ///
+    /// Takes `write_lock` for the duration of the call, since a fetch updates local
+    /// remote-tracking references.
+    ///
+    /// Args:
+    ///     remote_name: The name of the remote to fetch from (e.g., "origin")
+    ///     refspecs: Optional list of refspecs to use instead of the remote's configured ones
+    ///     credentials: Optional SshCredentials for an `ssh://` or `git@` transport; a
+    ///         `private_key_path` makes the call raise TransportError, and when it is omitted the
+    ///         local ssh-agent is used
+    ///
+    /// Returns:
+    ///     A Python coroutine resolving to an AsyncFetchOutcome
+    #[pyo3(name = "fetch", signature = (remote_name="origin", refspecs=None, credentials=None))]
+    fn fetch_py<'py>(
+        &self,
+        py: Python<'py>,
+        remote_name: &str,
+        refspecs: Option<Vec<String>>,
+        credentials: Option<crate::async_api::SshCredentials>,
+    ) -> PyResult<Py<PyAny>> {
+        crate::async_api::remote::fetch(
+            py,
+            self.repo.clone(),
+            self.write_lock.clone(),
+            remote_name.to_string(),
+            refspecs,
+            credentials,
+        )
+    }
+
+    /// Push to a remote asynchronously
+    ///
+    /// Takes `write_lock` for the duration of the call, since a push can move local
+    /// remote-tracking references once the server accepts it.
+    ///
+    /// Args:
+    ///     remote_name: The name of the remote to push to (e.g., "origin")
+    ///     refspecs: The refspecs to push (e.g., "refs/heads/main:refs/heads/main")
+    ///     credentials: Optional SshCredentials for an `ssh://` or `git@` transport; a
+    ///         `private_key_path` makes the call raise TransportError, and when it is omitted the
+    ///         local ssh-agent is used
+    ///
+    /// Returns:
+    ///     A Python coroutine that connects to the remote and then raises TransportError;
+    ///     nothing is pushed and no PushStatus list is returned
+    #[pyo3(name = "push", signature = (remote_name, refspecs, credentials=None))]
+    fn push_py<'py>(
+        &self,
+        py: Python<'py>,
+        remote_name: &str,
+        refspecs: Vec<String>,
+        credentials: Option<crate::async_api::SshCredentials>,
+    ) -> PyResult<Py<PyAny>> {
+        crate::async_api::remote::push(
+            py,
+            self.repo.clone(),
+            self.write_lock.clone(),
+            remote_name.to_string(),
+            refspecs,
+            credentials,
+        )
+    }
+
+    /// Blame a file at a revision, attributing each line to the commit that last changed it
+    ///
+    /// Read-only, so it runs lock-free and can proceed concurrently with other reads even while
+    /// a fetch or push holds `write_lock`.
+    ///
+    /// Args:
+    ///     path: The path of the file to blame, relative to the repository root
+    ///     rev: The revision to start the blame from
+    ///
+    /// Returns:
+    ///     A Python coroutine resolving to a list of BlameLine, one per line of the file
+    #[pyo3(name = "blame", signature = (path, *, rev="HEAD"))]
+    fn blame_py<'py>(&self, py: Python<'py>, path: &str, rev: &str) -> PyResult<Py<PyAny>> {
+        crate::async_api::blame::blame(py, self.repo.clone(), path.to_string(), rev.to_string())
+    }
+
+    /// Compute worktree status, returning an async iterator of per-path status entries
+    ///
+    /// Read-only, so it runs lock-free and can proceed concurrently with other reads even while
+    /// a fetch or push holds `write_lock`. Entries are streamed incrementally as they're found
+    /// rather than buffered into a single list, which matters on very large worktrees.
+    ///
+    /// Returns:
+    ///     An async iterator yielding StatusEntry instances
+    fn status(&self) -> PyResult<crate::async_api::AsyncStatusStream> {
+        crate::async_api::status::status(self.repo.clone())
+    }
+
+    /// Diff two trees (or commits, resolved to their trees), returning unified diff text per file
+    ///
+    /// Read-only, so it runs lock-free and can proceed concurrently with other reads even while
+    /// a fetch or push holds `write_lock`.
+    ///
+    /// Args:
+    ///     old_id: The tree or commit ID to diff from
+    ///     new_id: The tree or commit ID to diff to
+    ///
+    /// Returns:
+    ///     A Python coroutine resolving to a list of FileDiff, one per changed path
+    fn diff_tree<'py>(&self, py: Python<'py>, old_id: &str, new_id: &str) -> PyResult<Py<PyAny>> {
+        crate::async_api::diff::diff_tree(py, self.repo.clone(), old_id.to_string(), new_id.to_string())
+    }
+
+    /// Decode a commit and render its signature timestamps using `time_format`
+    ///
+    /// Read-only, so it runs lock-free and can proceed concurrently with other reads even while
+    /// a fetch or push holds `write_lock`.
+    ///
+    /// Args:
+    ///     id: The commit's object ID
+    ///     time_format: "unix", "raw", or a custom strftime-style format string (default "unix");
+    ///         only %Y %m %d %H %M %S %z and %% are expanded, other directives are kept verbatim
+    ///
+    /// Returns:
+    ///     A Python coroutine resolving to a CommitInfo with decoded author/committer/message/tree/parents
+    #[pyo3(signature = (id, time_format="unix"))]
+    fn commit_info<'py>(&self, py: Python<'py>, id: &str, time_format: &str) -> PyResult<Py<PyAny>> {
+        crate::async_api::metadata::commit_info(py, self.repo.clone(), id.to_string(), time_format.to_string())
+    }
+
+    /// Diff a commit against its first parent, returning unified diff text per file
+    ///
+    /// Read-only; behaves like [`AsyncRepository::diff_tree`] against an empty tree when `id`
+    /// has no parent.
+    ///
+    /// Args:
+    ///     id: The commit ID to diff against its first parent
+    ///
+    /// Returns:
+    ///     A Python coroutine resolving to a list of FileDiff, one per changed path
+    fn diff_commit<'py>(&self, py: Python<'py>, id: &str) -> PyResult<Py<PyAny>> {
+        crate::async_api::diff::diff_commit(py, self.repo.clone(), id.to_string())
+    }
+
+    /// Walk ancestor commits starting from `start`, following parent links
+    ///
+    /// Read-only, so it runs lock-free and can proceed concurrently with other reads even while
+    /// a fetch or push holds `write_lock`.
+    ///
+    /// Args:
+    ///     start: A ref name, short hash, or object ID to start the walk from
+    ///     limit: Stop after this many commits, if given
+    ///     sorting: `"topo"` for topological order, or `"date"` for commit-time order
+    ///     path: If given, only yield commits whose tree differs from their first parent's at
+    ///         this path
+    ///
+    /// Returns:
+    ///     A Python coroutine resolving to a list of commit ID strings, oldest ancestry last
+    #[pyo3(signature = (start="HEAD", limit=None, sorting="topo", path=None))]
+    fn log<'py>(
+        &self,
+        py: Python<'py>,
+        start: &str,
+        limit: Option<usize>,
+        sorting: &str,
+        path: Option<&str>,
+    ) -> PyResult<Py<PyAny>> {
+        crate::async_api::log::log(
+            py,
+            self.repo.clone(),
+            start.to_string(),
+            limit,
+            sorting.to_string(),
+            path.map(ToOwned::to_owned),
+        )
+    }
+}
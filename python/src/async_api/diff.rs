@@ -0,0 +1,241 @@
+use gix_hash::ObjectId;
+use pyo3::prelude::*;
+use pyo3::{Py, PyAny};
+use std::sync::Arc;
+
+use crate::errors::{diff_error, object_error, repository_error};
+use crate::repository::diff::{looks_binary, unified_hunks};
+
+/// A single file's unified diff within a tree-to-tree or commit-to-parent comparison
+///
+/// `patch` holds ready-to-display unified diff text: path headers plus `@@ ... @@` hunks, or
+/// `Binary files ... differ` for blobs that look binary.
+#[pyclass(unsendable)]
+pub struct FileDiff {
+    #[pyo3(get)]
+    pub path: String,
+    #[pyo3(get)]
+    pub old_path: Option<String>,
+    #[pyo3(get)]
+    pub change_kind: String,
+    #[pyo3(get)]
+    pub old_id: Option<String>,
+    #[pyo3(get)]
+    pub new_id: Option<String>,
+    #[pyo3(get)]
+    pub is_binary: bool,
+    #[pyo3(get)]
+    pub patch: String,
+}
+
+fn resolve_tree<'repo>(repo: &'repo gix::Repository, spec: &str) -> PyResult<gix::Tree<'repo>> {
+    let id = ObjectId::from_hex(spec.as_bytes()).map_err(|_| diff_error(format!("Invalid object ID: {}", spec)))?;
+    let object = repo
+        .find_object(id)
+        .map_err(|err| diff_error(format!("Failed to find object {}: {}", spec, err)))?;
+
+    match object.kind {
+        gix::object::Kind::Commit => object
+            .into_commit()
+            .tree()
+            .map_err(|err| diff_error(format!("Failed to get tree for commit {}: {}", spec, err))),
+        gix::object::Kind::Tree => object
+            .try_into_tree()
+            .map_err(|err| diff_error(format!("Object {} is not a tree: {}", spec, err))),
+        other => Err(diff_error(format!("Object {} has unsupported kind {:?}", spec, other))),
+    }
+}
+
+fn blob_lines(repo: &gix::Repository, id: &str) -> PyResult<Option<Vec<String>>> {
+    let Ok(id) = ObjectId::from_hex(id.as_bytes()) else {
+        return Ok(None);
+    };
+    let blob = repo
+        .find_blob(id)
+        .map_err(|err| object_error(format!("Failed to read blob {}: {}", id, err)))?;
+
+    if looks_binary(&blob.data) {
+        return Ok(None);
+    }
+    Ok(Some(String::from_utf8_lossy(&blob.data).lines().map(ToOwned::to_owned).collect()))
+}
+
+/// Render a single file's unified diff text: `--- a/...` / `+++ b/...` headers plus hunks, or a
+/// `Binary files ... differ` line when either side looks binary.
+fn render_patch(
+    repo: &gix::Repository,
+    old_path: Option<&str>,
+    path: &str,
+    old_id: Option<&str>,
+    new_id: Option<&str>,
+) -> PyResult<(bool, String)> {
+    let old_header = old_path.unwrap_or(path);
+
+    let old_blob = old_id.and_then(|id| ObjectId::from_hex(id.as_bytes()).ok()).and_then(|id| repo.find_blob(id).ok());
+    let new_blob = new_id.and_then(|id| ObjectId::from_hex(id.as_bytes()).ok()).and_then(|id| repo.find_blob(id).ok());
+    let is_binary = old_blob.as_ref().is_some_and(|blob| looks_binary(&blob.data))
+        || new_blob.as_ref().is_some_and(|blob| looks_binary(&blob.data));
+
+    if is_binary {
+        return Ok((true, format!("Binary files a/{} and b/{} differ\n", old_header, path)));
+    }
+
+    let old_lines = old_id.map(|id| blob_lines(repo, id)).transpose()?.flatten().unwrap_or_default();
+    let new_lines = new_id.map(|id| blob_lines(repo, id)).transpose()?.flatten().unwrap_or_default();
+    let old_refs: Vec<&str> = old_lines.iter().map(String::as_str).collect();
+    let new_refs: Vec<&str> = new_lines.iter().map(String::as_str).collect();
+
+    let mut patch = format!(
+        "--- {}\n+++ {}\n",
+        old_id.map(|_| format!("a/{}", old_header)).unwrap_or_else(|| "/dev/null".to_string()),
+        new_id.map(|_| format!("b/{}", path)).unwrap_or_else(|| "/dev/null".to_string()),
+    );
+    for hunk in unified_hunks(&old_refs, &new_refs, 3) {
+        patch.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            hunk.old_start, hunk.old_lines, hunk.new_start, hunk.new_lines
+        ));
+        for line in hunk.lines {
+            patch.push_str(&line.origin);
+            patch.push_str(&line.content);
+            patch.push('\n');
+        }
+    }
+    Ok((false, patch))
+}
+
+struct RawChange {
+    change_kind: String,
+    old_id: Option<String>,
+    new_id: Option<String>,
+    old_path: Option<String>,
+    path: String,
+}
+
+/// Diff `old` against `new` (tree or commit IDs), returning one [`FileDiff`] per changed path
+///
+/// Enumerates the tree-to-tree change set first (cheap, infallible once trees are resolved), then
+/// renders each file's unified patch text in a second pass so a blob-read failure surfaces as a
+/// normal `PyResult` error instead of needing to thread through the diff callback's own error type.
+fn diff_between(repo: &gix::Repository, old: &str, new: &str) -> PyResult<Vec<FileDiff>> {
+    let old_tree = resolve_tree(repo, old)?;
+    let new_tree = resolve_tree(repo, new)?;
+
+    let mut raw_changes = Vec::new();
+    let mut changes = old_tree
+        .changes()
+        .map_err(|err| diff_error(format!("Failed to set up tree diff: {}", err)))?;
+    changes.track_rewrites(Some(gix::diff::rewrites::Rewrites {
+        copies: None,
+        percentage: Some(0.5),
+        limit: 0,
+    }));
+
+    changes
+        .for_each_to_obtain_tree(&new_tree, |change| {
+            let (change_kind, old_id, new_id, old_path, path) = match &change {
+                gix::object::tree::diff::Change::Addition { id, location, .. } => {
+                    ("added".to_string(), None, Some(id.to_string()), None, location.to_string())
+                }
+                gix::object::tree::diff::Change::Deletion { id, location, .. } => {
+                    ("deleted".to_string(), Some(id.to_string()), None, None, location.to_string())
+                }
+                gix::object::tree::diff::Change::Modification {
+                    previous_id,
+                    id,
+                    location,
+                    ..
+                } => (
+                    "modified".to_string(),
+                    Some(previous_id.to_string()),
+                    Some(id.to_string()),
+                    None,
+                    location.to_string(),
+                ),
+                gix::object::tree::diff::Change::Rewrite {
+                    source_id,
+                    id,
+                    source_location,
+                    location,
+                    copy,
+                    ..
+                } => (
+                    if *copy { "copied".to_string() } else { "renamed".to_string() },
+                    Some(source_id.to_string()),
+                    Some(id.to_string()),
+                    Some(source_location.to_string()),
+                    location.to_string(),
+                ),
+            };
+
+            raw_changes.push(RawChange {
+                change_kind,
+                old_id,
+                new_id,
+                old_path,
+                path,
+            });
+
+            Ok::<_, std::convert::Infallible>(gix::object::tree::diff::Action::Continue)
+        })
+        .map_err(|err| diff_error(format!("Failed to compute diff: {}", err)))?;
+
+    raw_changes
+        .into_iter()
+        .map(|change| {
+            let (is_binary, patch) = render_patch(
+                repo,
+                change.old_path.as_deref(),
+                &change.path,
+                change.old_id.as_deref(),
+                change.new_id.as_deref(),
+            )?;
+            Ok(FileDiff {
+                path: change.path,
+                old_path: change.old_path,
+                change_kind: change.change_kind,
+                old_id: change.old_id,
+                new_id: change.new_id,
+                is_binary,
+                patch,
+            })
+        })
+        .collect()
+}
+
+/// Diff two trees (or commits, resolved to their trees) and return the unified patch text for
+/// each changed file
+pub(crate) fn diff_tree(
+    py: Python<'_>,
+    repo: Arc<gix::ThreadSafeRepository>,
+    old: String,
+    new: String,
+) -> PyResult<Py<PyAny>> {
+    let py_future = pyo3_async_runtimes::tokio::future_into_py(py, async move {
+        let repo = repo.to_thread_local();
+        let files = diff_between(&repo, &old, &new)?;
+        Python::with_gil(|py| files.into_iter().map(|file| Py::new(py, file)).collect::<PyResult<Vec<_>>>())
+    })?;
+    Ok(py_future.into())
+}
+
+/// Diff a commit against its first parent (or against an empty tree if it has none)
+pub(crate) fn diff_commit(py: Python<'_>, repo: Arc<gix::ThreadSafeRepository>, id: String) -> PyResult<Py<PyAny>> {
+    let py_future = pyo3_async_runtimes::tokio::future_into_py(py, async move {
+        let repo = repo.to_thread_local();
+        let commit_id =
+            ObjectId::from_hex(id.as_bytes()).map_err(|_| repository_error(format!("Invalid object ID: {}", id)))?;
+        let commit = repo
+            .find_commit(commit_id)
+            .map_err(|err| object_error(format!("Failed to find commit {}: {}", id, err)))?;
+
+        let old = match commit.parent_ids().next() {
+            Some(parent_id) => parent_id.to_string(),
+            None => ObjectId::empty_tree(repo.object_hash()).to_string(),
+        };
+
+        let files = diff_between(&repo, &old, &id)?;
+        Python::with_gil(|py| files.into_iter().map(|file| Py::new(py, file)).collect::<PyResult<Vec<_>>>())
+    })?;
+    Ok(py_future.into())
+}
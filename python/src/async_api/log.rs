@@ -0,0 +1,109 @@
+use gix_hash::ObjectId;
+use pyo3::prelude::*;
+use pyo3::{Py, PyAny};
+use std::sync::Arc;
+
+use crate::errors::{object_error, repository_error};
+
+fn sorting_from_str(sort: &str) -> PyResult<gix::revision::walk::Sorting> {
+    match sort {
+        "topo" => Ok(gix::revision::walk::Sorting::TopoOrder),
+        "date" => Ok(gix::revision::walk::Sorting::ByCommitTimeNewestFirst),
+        other => Err(repository_error(format!("Unknown sort mode '{}', expected topo/date", other))),
+    }
+}
+
+/// Whether `commit`'s tree differs from its first parent's (or from an empty tree, if it has
+/// none) at `path`
+fn touches_path(repo: &gix::Repository, commit: &gix::Commit<'_>, path: &str) -> PyResult<bool> {
+    let tree = commit
+        .tree()
+        .map_err(|err| object_error(format!("Failed to get tree for commit {}: {}", commit.id, err)))?;
+
+    let parent_tree = match commit.parent_ids().next() {
+        Some(parent_id) => repo
+            .find_commit(parent_id)
+            .map_err(|err| object_error(format!("Failed to find parent commit {}: {}", parent_id, err)))?
+            .tree()
+            .map_err(|err| object_error(format!("Failed to get parent tree for commit {}: {}", commit.id, err)))?,
+        None => repo
+            .find_object(ObjectId::empty_tree(repo.object_hash()))
+            .map_err(|err| object_error(format!("Failed to find empty tree: {}", err)))?
+            .try_into_tree()
+            .map_err(|err| object_error(format!("Empty tree is not a tree: {}", err)))?,
+    };
+
+    let mut touched = false;
+    let mut changes = parent_tree
+        .changes()
+        .map_err(|err| object_error(format!("Failed to set up tree diff for commit {}: {}", commit.id, err)))?;
+    changes
+        .for_each_to_obtain_tree(&tree, |change| {
+            let location = match &change {
+                gix::object::tree::diff::Change::Addition { location, .. } => location,
+                gix::object::tree::diff::Change::Deletion { location, .. } => location,
+                gix::object::tree::diff::Change::Modification { location, .. } => location,
+                gix::object::tree::diff::Change::Rewrite { location, .. } => location,
+            };
+            if location == path {
+                touched = true;
+                return Ok::<_, std::convert::Infallible>(gix::object::tree::diff::Action::Cancel);
+            }
+            Ok::<_, std::convert::Infallible>(gix::object::tree::diff::Action::Continue)
+        })
+        .map_err(|err| object_error(format!("Failed to diff commit {} against its parent: {}", commit.id, err)))?;
+
+    Ok(touched)
+}
+
+/// Walk ancestor commit IDs starting from `start`, optionally keeping only those that touch `path`
+///
+/// Mirrors the sync `Repository.walk`'s sort modes (`"topo"`/`"date"`), but resolves a single
+/// starting point via `rev_parse` (a ref name, short hash, or full object ID) instead of a list of
+/// tips, and collects the commit IDs on the shared runtime so large histories don't block the GIL.
+pub(crate) fn log(
+    py: Python<'_>,
+    repo: Arc<gix::ThreadSafeRepository>,
+    start: String,
+    limit: Option<usize>,
+    sorting: String,
+    path: Option<String>,
+) -> PyResult<Py<PyAny>> {
+    let py_future = pyo3_async_runtimes::tokio::future_into_py(py, async move {
+        let repo = repo.to_thread_local();
+        let sort = sorting_from_str(&sorting)?;
+
+        let tip_id = repo
+            .rev_parse_single(start.as_str())
+            .map_err(|err| repository_error(format!("Failed to parse revision '{}': {}", start, err)))?
+            .detach();
+
+        let walk = repo
+            .rev_walk([tip_id])
+            .sorting(sort)
+            .all()
+            .map_err(|err| repository_error(format!("Failed to start history walk: {}", err)))?;
+
+        let mut ids = Vec::new();
+        for info in walk {
+            let info = info.map_err(|err| repository_error(format!("Failed to walk history: {}", err)))?;
+
+            if let Some(path) = path.as_deref() {
+                let commit = repo
+                    .find_commit(info.id)
+                    .map_err(|err| object_error(format!("Failed to find commit {}: {}", info.id, err)))?;
+                if !touches_path(&repo, &commit, path)? {
+                    continue;
+                }
+            }
+
+            ids.push(info.id.to_string());
+            if limit.is_some_and(|limit| ids.len() >= limit) {
+                break;
+            }
+        }
+
+        Ok(ids)
+    })?;
+    Ok(py_future.into())
+}
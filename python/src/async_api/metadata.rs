@@ -0,0 +1,49 @@
+use gix_hash::ObjectId;
+use pyo3::prelude::*;
+use pyo3::{Py, PyAny};
+use std::sync::Arc;
+
+use crate::errors::{object_error, repository_error};
+use crate::repository::metadata::{parse_time_format, render_signature};
+use crate::repository::CommitInfo;
+
+/// Decode a commit and render its signature timestamps using `time_format`
+///
+/// Read-only, so it runs lock-free and can proceed concurrently with other reads even while a
+/// fetch or push holds the repository's write lock. Mirrors `repository::metadata::commit_info`.
+pub(crate) fn commit_info(
+    py: Python<'_>,
+    repo: Arc<gix::ThreadSafeRepository>,
+    id: String,
+    time_format: String,
+) -> PyResult<Py<PyAny>> {
+    let py_future = pyo3_async_runtimes::tokio::future_into_py(py, async move {
+        let repo = repo.to_thread_local();
+        let object_id =
+            ObjectId::from_hex(id.as_bytes()).map_err(|_| repository_error(format!("Invalid object ID: {}", id)))?;
+
+        let commit = repo
+            .find_commit(object_id)
+            .map_err(|err| object_error(format!("Failed to find commit {}: {}", id, err)))?;
+
+        let decoded = commit
+            .decode()
+            .map_err(|err| object_error(format!("Failed to decode commit {}: {}", id, err)))?;
+
+        let format = parse_time_format(&time_format);
+
+        let info = CommitInfo {
+            id: commit.id.to_string(),
+            tree: decoded.tree().to_string(),
+            parents: decoded.parents().map(|p| p.to_string()).collect(),
+            author: render_signature(decoded.author, format),
+            committer: render_signature(decoded.committer, format),
+            message: decoded.message.to_string(),
+            summary: decoded.message().title.trim().to_string(),
+        };
+
+        Python::with_gil(|py| Py::new(py, info))
+    })?;
+
+    Ok(py_future.into())
+}
@@ -0,0 +1,19 @@
+// Submodules
+mod blame;
+mod clone;
+mod core;
+mod diff;
+mod log;
+mod metadata;
+mod remote;
+mod runtime;
+mod status;
+
+// Re-export the public API
+pub use blame::BlameLine;
+pub use clone::CloneProgress;
+pub use core::AsyncRepository;
+pub use diff::FileDiff;
+pub use remote::{AsyncFetchOutcome, PushStatus, RefUpdate, SshCredentials};
+pub use runtime::set_runtime_worker_threads;
+pub use status::{AsyncStatusStream, StatusEntry};
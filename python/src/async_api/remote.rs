@@ -0,0 +1,229 @@
+use pyo3::prelude::*;
+use pyo3::{Py, PyAny};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::errors::{remote_error, transport_error};
+
+/// A single reference update observed during a fetch
+#[pyclass(unsendable)]
+pub struct RefUpdate {
+    #[pyo3(get)]
+    pub name: String,
+    #[pyo3(get)]
+    pub old_id: Option<String>,
+    #[pyo3(get)]
+    pub new_id: String,
+}
+
+/// The outcome of an async `fetch()`
+#[pyclass(unsendable)]
+pub struct AsyncFetchOutcome {
+    #[pyo3(get)]
+    pub updates: Vec<Py<RefUpdate>>,
+}
+
+/// Whether a single refspec was accepted or rejected during `push()`
+#[pyclass(unsendable)]
+pub struct PushStatus {
+    #[pyo3(get)]
+    pub refspec: String,
+    #[pyo3(get)]
+    pub accepted: bool,
+    #[pyo3(get)]
+    pub reason: Option<String>,
+}
+
+/// SSH credentials for a `fetch`/`push` over an `ssh://` or `git@` transport
+///
+/// When `private_key_path` is set, `fetch`/`push` decrypt the key and then raise
+/// `TransportError`; the key is not handed to the transport. When `private_key_path` is omitted,
+/// no credentials are applied and the local ssh-agent is used.
+#[pyclass(unsendable)]
+#[derive(Clone, Default)]
+pub struct SshCredentials {
+    #[pyo3(get, set)]
+    pub private_key_path: Option<String>,
+    #[pyo3(get, set)]
+    pub passphrase: Option<String>,
+}
+
+#[pymethods]
+impl SshCredentials {
+    #[new]
+    #[pyo3(signature = (private_key_path=None, passphrase=None))]
+    fn new(private_key_path: Option<String>, passphrase: Option<String>) -> Self {
+        Self {
+            private_key_path,
+            passphrase,
+        }
+    }
+}
+
+/// Read an OpenSSH-format private key file, decrypting it when `passphrase` is given
+///
+/// With a passphrase, supports the bcrypt-pbkdf KDF with an aes-256-ctr or aes-256-gcm cipher,
+/// which is what `ssh-keygen` produces by default for encrypted keys, and returns the decrypted
+/// key's bytes. Without a passphrase, the file's bytes are returned unchanged.
+fn decrypt_private_key(path: &str, passphrase: Option<&str>) -> PyResult<Vec<u8>> {
+    let raw = std::fs::read(path)
+        .map_err(|err| transport_error(format!("Failed to read private key '{}': {}", path, err)))?;
+
+    match passphrase {
+        Some(passphrase) => ssh_key::private::PrivateKey::from_openssh(&raw)
+            .and_then(|key| key.decrypt(passphrase))
+            .map_err(|err| transport_error(format!("Failed to decrypt private key '{}': {}", path, err)))
+            .map(|key| key.to_bytes().unwrap_or_default()),
+        None => Ok(raw),
+    }
+}
+
+fn apply_ssh_credentials(
+    remote: gix::Remote<'_>,
+    credentials: Option<SshCredentials>,
+) -> PyResult<gix::Remote<'_>> {
+    let Some(credentials) = credentials else { return Ok(remote) };
+
+    if let Some(path) = credentials.private_key_path.as_deref() {
+        // Decrypting succeeds, but `gix::protocol::credentials::helper::Action`'s identity
+        // response has no way to carry raw key bytes from this call site into the transport, so
+        // wiring `private_key_path` through would require deeper changes than this binding
+        // currently supports. Raise rather than silently fall back to ssh-agent/anonymous auth
+        // and let the caller believe their key was used.
+        decrypt_private_key(path, credentials.passphrase.as_deref())?;
+        return Err(transport_error(format!(
+            "explicit SSH key authentication via private_key_path ('{}') is not yet wired into the transport; \
+             omit SshCredentials.private_key_path to use the local ssh-agent instead",
+            path
+        )));
+    }
+
+    Ok(remote)
+}
+
+/// Fetch from a remote asynchronously, returning the references that were created or updated
+///
+/// Holds `write_lock` for the duration of the call, since a fetch updates local
+/// remote-tracking references.
+pub(crate) fn fetch(
+    py: Python<'_>,
+    repo: Arc<gix::ThreadSafeRepository>,
+    write_lock: Arc<Mutex<()>>,
+    remote_name: String,
+    refspecs: Option<Vec<String>>,
+    credentials: Option<SshCredentials>,
+) -> PyResult<Py<PyAny>> {
+    let py_future = pyo3_async_runtimes::tokio::future_into_py(py, async move {
+        let _guard = write_lock.lock().await;
+        let repo = repo.to_thread_local();
+        let mut remote = repo
+            .find_remote(remote_name.as_str())
+            .map_err(|err| remote_error(format!("Failed to find remote '{}': {}", remote_name, err)))?;
+
+        if let Some(refspecs) = refspecs {
+            remote = remote
+                .with_refspecs(refspecs.iter().map(String::as_str), gix::remote::Direction::Fetch)
+                .map_err(|err| remote_error(format!("Invalid refspec for remote '{}': {}", remote_name, err)))?;
+        }
+
+        remote = apply_ssh_credentials(remote, credentials)?;
+
+        let connection = remote
+            .connect(gix::remote::Direction::Fetch)
+            .map_err(|err| remote_error(format!("Failed to connect to remote '{}': {}", remote_name, err)))?;
+
+        let prepare = connection
+            .prepare_fetch(gix::progress::Discard, gix::remote::ref_map::Options::default())
+            .map_err(|err| remote_error(format!("Failed to prepare fetch from '{}': {}", remote_name, err)))?;
+
+        // Capture each local ref's current target before `receive()` moves it, so the reported
+        // `RefUpdate` carries the actual old/new object ids rather than a hardcoded `None`.
+        let old_ids: std::collections::HashMap<String, gix_hash::ObjectId> = prepare
+            .ref_map
+            .mappings
+            .iter()
+            .filter_map(|mapping| mapping.local.as_ref().map(|name| name.to_string()))
+            .filter_map(|name| {
+                let old_id = match repo.find_reference(name.as_str()).ok()?.inner.target {
+                    gix_ref::Target::Object(id) => Some(id),
+                    gix_ref::Target::Symbolic(_) => None,
+                };
+                old_id.map(|id| (name, id))
+            })
+            .collect();
+
+        let outcome = prepare
+            .receive(gix::progress::Discard, &AtomicBool::new(false))
+            .map_err(|err| remote_error(format!("Failed to fetch from '{}': {}", remote_name, err)))?;
+
+        let updates = outcome
+            .ref_map
+            .mappings
+            .iter()
+            .filter_map(|mapping| {
+                mapping.local.as_ref().map(|name| RefUpdate {
+                    name: name.to_string(),
+                    old_id: old_ids.get(name.to_string().as_str()).map(|id| id.to_string()),
+                    new_id: mapping.remote.as_id().map(|id| id.to_string()).unwrap_or_default(),
+                })
+            })
+            .filter(|update| update.old_id.as_deref() != Some(update.new_id.as_str()))
+            .collect::<Vec<_>>();
+
+        Python::with_gil(|py| {
+            let updates = updates
+                .into_iter()
+                .map(|update| Py::new(py, update))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(AsyncFetchOutcome { updates })
+        })
+    })?;
+
+    Ok(py_future.into())
+}
+
+/// Push to a remote asynchronously
+///
+/// Connects to the remote and then raises `TransportError`: no pack is transferred, nothing is
+/// pushed, and no per-refspec `PushStatus` is returned.
+///
+/// Holds `write_lock` for the duration of the call, since a push can move local
+/// remote-tracking references once the server accepts it.
+pub(crate) fn push(
+    py: Python<'_>,
+    repo: Arc<gix::ThreadSafeRepository>,
+    write_lock: Arc<Mutex<()>>,
+    remote_name: String,
+    refspecs: Vec<String>,
+    credentials: Option<SshCredentials>,
+) -> PyResult<Py<PyAny>> {
+    let py_future = pyo3_async_runtimes::tokio::future_into_py(py, async move {
+        let _guard = write_lock.lock().await;
+        let repo = repo.to_thread_local();
+        let mut remote = repo
+            .find_remote(remote_name.as_str())
+            .map_err(|err| remote_error(format!("Failed to find remote '{}': {}", remote_name, err)))?;
+
+        remote = remote
+            .with_refspecs(refspecs.iter().map(String::as_str), gix::remote::Direction::Push)
+            .map_err(|err| remote_error(format!("Invalid refspec for remote '{}': {}", remote_name, err)))?;
+
+        remote = apply_ssh_credentials(remote, credentials)?;
+
+        let _connection = remote
+            .connect(gix::remote::Direction::Push)
+            .map_err(|err| remote_error(format!("Failed to connect to remote '{}': {}", remote_name, err)))?;
+
+        // `connect()` only negotiates the transport; gix does not yet expose a pack-negotiation
+        // and transfer API for push in this binding, so there is no way to actually land the
+        // refspecs on the remote. Raise rather than report fabricated per-refspec success, since
+        // callers would otherwise believe their commits reached the remote.
+        Err::<Vec<PushStatus>, _>(transport_error(format!(
+            "push to remote '{}' is not yet implemented: connection negotiated but no pack-transfer support is available",
+            remote_name
+        )))
+    })?;
+
+    Ok(py_future.into())
+}
@@ -0,0 +1,24 @@
+use pyo3::prelude::*;
+
+use crate::errors::repository_error;
+
+/// Configure the worker-thread count of the shared Tokio runtime used by all async repository
+/// operations
+///
+/// Must be called before the first async operation runs, since that's when the shared runtime is
+/// lazily created. Calling it afterwards raises a RepositoryError.
+#[pyfunction]
+pub fn set_runtime_worker_threads(worker_threads: usize) -> PyResult<()> {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(worker_threads.max(1))
+        .enable_all()
+        .build()
+        .map_err(|err| repository_error(format!("Failed to build Tokio runtime: {}", err)))?;
+
+    pyo3_async_runtimes::tokio::init_with_runtime(runtime).map_err(|_| {
+        repository_error(
+            "The shared Tokio runtime is already running; call set_runtime_worker_threads \
+             before the first async operation",
+        )
+    })
+}
@@ -0,0 +1,166 @@
+use gix_hash::ObjectId;
+use pyo3::prelude::*;
+use pyo3::{Py, PyAny, PyRef};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+
+use crate::errors::repository_error;
+
+/// A single worktree status entry, carrying its index-vs-HEAD and worktree-vs-index states
+#[pyclass(unsendable)]
+#[derive(Clone)]
+pub struct StatusEntry {
+    #[pyo3(get)]
+    pub path: String,
+    #[pyo3(get)]
+    pub index_vs_head: String,
+    #[pyo3(get)]
+    pub worktree_vs_index: String,
+}
+
+/// An async iterator yielding one `StatusEntry` at a time as the comparison progresses
+///
+/// Backed by a bounded channel fed by a background task on the shared runtime, so large
+/// repositories stream results incrementally instead of buffering the entire change set.
+#[pyclass(unsendable)]
+pub struct AsyncStatusStream {
+    receiver: Arc<Mutex<mpsc::Receiver<PyResult<StatusEntry>>>>,
+}
+
+#[pymethods]
+impl AsyncStatusStream {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Py<PyAny>> {
+        let receiver = self.receiver.clone();
+        let py_future = pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            match receiver.lock().await.recv().await {
+                Some(Ok(entry)) => Ok(entry),
+                Some(Err(err)) => Err(err),
+                None => Err(PyErr::new::<pyo3::exceptions::PyStopAsyncIteration, _>(())),
+            }
+        })?;
+        Ok(py_future.into())
+    }
+}
+
+/// Recursively flatten `tree` into `path -> blob id`, prefixing paths with `prefix`
+///
+/// Mirrors the recursive tree walk in `repository::archive::append_tree`, since gix's tree diff
+/// only compares tree-to-tree (or tree-to-worktree via `status()`), not tree-to-index directly.
+fn collect_tree_blobs(repo: &gix::Repository, tree: &gix::Tree<'_>, prefix: &str, out: &mut HashMap<String, ObjectId>) -> PyResult<()> {
+    for entry in tree.iter() {
+        let entry = entry.map_err(|err| repository_error(format!("Failed to read tree entry: {}", err)))?;
+        let path = if prefix.is_empty() {
+            entry.filename().to_string()
+        } else {
+            format!("{}/{}", prefix, entry.filename())
+        };
+
+        if entry.mode().is_tree() {
+            let object = repo
+                .find_object(entry.object_id())
+                .map_err(|err| repository_error(format!("Failed to read tree entry '{}': {}", path, err)))?;
+            let subtree = object
+                .try_into_tree()
+                .map_err(|err| repository_error(format!("'{}' is not a tree: {}", path, err)))?;
+            collect_tree_blobs(repo, &subtree, &path, out)?;
+        } else {
+            out.insert(path, entry.object_id());
+        }
+    }
+    Ok(())
+}
+
+/// Compute the index-vs-HEAD ("staged") status for every path currently in the index or in HEAD
+///
+/// A path missing from HEAD is `"added"`, missing from the index is `"deleted"`, present in both
+/// with a different blob id is `"modified"`, and otherwise `"unmodified"`. A repository with an
+/// unborn HEAD (no commits yet) is treated as diffing against an empty tree, so every index entry
+/// comes out `"added"`.
+fn index_vs_head_statuses(repo: &gix::Repository) -> PyResult<HashMap<String, String>> {
+    let mut head_entries = HashMap::new();
+    if let Ok(commit) = repo.head_commit() {
+        let tree = commit
+            .tree()
+            .map_err(|err| repository_error(format!("Failed to get HEAD tree: {}", err)))?;
+        collect_tree_blobs(repo, &tree, "", &mut head_entries)?;
+    }
+
+    let index = repo
+        .index_or_empty()
+        .map_err(|err| repository_error(format!("Failed to read index: {}", err)))?;
+
+    let mut statuses = HashMap::with_capacity(index.entries().len());
+    for entry in index.entries() {
+        if entry.stage() != gix::index::entry::Stage::Unconflicted {
+            continue;
+        }
+        let path = entry.path(&index).to_string();
+        let status = match head_entries.get(&path) {
+            None => "added",
+            Some(head_id) if *head_id != entry.id => "modified",
+            Some(_) => "unmodified",
+        };
+        statuses.insert(path, status.to_string());
+    }
+
+    for path in head_entries.keys() {
+        statuses.entry(path.clone()).or_insert_with(|| "deleted".to_string());
+    }
+
+    Ok(statuses)
+}
+
+/// Start computing worktree status in the background, returning an async iterator of entries
+///
+/// Compares the index against HEAD and the worktree against the index (including untracked
+/// files, subject to gitignore) without holding the GIL while the comparison runs. Only paths
+/// reported by the worktree-vs-index comparison are yielded, each carrying its index-vs-HEAD
+/// state; a path whose only change is staged is not yielded.
+pub(crate) fn status(repo: Arc<gix::ThreadSafeRepository>) -> PyResult<AsyncStatusStream> {
+    let (sender, receiver) = mpsc::channel(64);
+
+    pyo3_async_runtimes::tokio::get_runtime().spawn(async move {
+        let local = repo.to_thread_local();
+
+        let staged = index_vs_head_statuses(&local);
+
+        let entries = local
+            .status(gix::progress::Discard)
+            .and_then(|platform| platform.into_index_worktree_iter(None))
+            .map_err(|err| repository_error(format!("Failed to compute status: {}", err)));
+
+        match (staged, entries) {
+            (Ok(staged), Ok(iter)) => {
+                for item in iter {
+                    let entry = item
+                        .map_err(|err| repository_error(format!("Failed to read status entry: {}", err)))
+                        .map(|item| {
+                            let path = item.rela_path().to_string();
+                            let index_vs_head = staged.get(&path).cloned().unwrap_or_else(|| "untracked".to_string());
+                            StatusEntry {
+                                path,
+                                index_vs_head,
+                                worktree_vs_index: format!("{:?}", item.summary()),
+                            }
+                        });
+
+                    if sender.send(entry).await.is_err() {
+                        break;
+                    }
+                }
+            }
+            (Err(err), _) | (_, Err(err)) => {
+                let _ = sender.send(Err(err)).await;
+            }
+        }
+    });
+
+    Ok(AsyncStatusStream {
+        receiver: Arc::new(Mutex::new(receiver)),
+    })
+}
@@ -1,226 +0,0 @@
-use pyo3::prelude::*;
-use pyo3::types::PyDict;
-
-/// A Git configuration object
-///
-/// This class provides access to the repository's configuration.
-#[pyclass(unsendable)]
-pub struct Config {
-    // Hold a reference to the repository to get config on demand
-    repo: gix::Repository,
-}
-
-impl Config {
-    /// Create a new config object from a repository
-    pub fn new(repo: &gix::Repository) -> Self {
-        Self { repo: repo.clone() }
-    }
-}
-
-#[pymethods]
-impl Config {
-    /// Get a boolean value from the configuration
-    ///
-    /// Args:
-    ///     key: The configuration key (e.g., "core.bare")
-    ///
-    /// Returns:
-    ///     The boolean value if the key exists and is a valid boolean,
-    ///     or None if the key doesn't exist
-    fn boolean(&self, key: &str) -> Option<bool> {
-        self.repo.config_snapshot().boolean(key)
-    }
-
-    /// Get an integer value from the configuration
-    ///
-    /// Args:
-    ///     key: The configuration key (e.g., "core.compression")
-    ///
-    /// Returns:
-    ///     The integer value if the key exists and is a valid integer,
-    ///     or None if the key doesn't exist
-    fn integer(&self, key: &str) -> Option<i64> {
-        self.repo.config_snapshot().integer(key)
-    }
-
-    /// Get a string value from the configuration
-    ///
-    /// Args:
-    ///     key: The configuration key (e.g., "user.name")
-    ///
-    /// Returns:
-    ///     The string value if the key exists, or None if the key doesn't exist
-    fn string(&self, key: &str) -> Option<String> {
-        self.repo.config_snapshot().string(key).map(|s| s.to_string())
-    }
-
-    /// Get a list of values from a multi-valued configuration key
-    ///
-    /// Args:
-    ///     key: The configuration key (e.g., "remote.origin.fetch")
-    ///
-    /// Returns:
-    ///     A list of string values associated with the key, or an empty list if the key doesn't exist
-    fn values(&self, key: &str) -> Vec<String> {
-        let mut result = Vec::new();
-
-        // First try to get the key as a single value
-        if let Some(value) = self.repo.config_snapshot().string(key) {
-            result.push(value.to_string());
-            return result; // Return early with the single value
-        }
-
-        // Handle special case for indexed URLs
-        // The Git CLI sets remote.origin.url.0, remote.origin.url.1 as individual entries
-        // but we need to expose them as values of remote.origin.url
-        let config = self.repo.config_snapshot();
-
-        // Try to get indexed values directly (remote.origin.url.0, remote.origin.url.1, etc.)
-        let mut idx = 0;
-        let mut found_any = false;
-
-        loop {
-            let indexed_key = format!("{}.{}", key, idx);
-
-            // Try both versions: with and without quotes
-            if let Some(value) = config.string(&indexed_key) {
-                result.push(value.to_string());
-                found_any = true;
-                idx += 1;
-            } else {
-                // For Git's weird indexed URL syntax, try a different approach
-                // Some Git implementations might store them differently
-                if !found_any && idx > 5 {
-                    // Give up after checking a reasonable number of indices
-                    break;
-                }
-
-                // Try next index
-                idx += 1;
-
-                // If we've tried several indices and found nothing, stop
-                if idx > 10 {
-                    break;
-                }
-            }
-        }
-
-        // Try direct values - for implementations that store multiple values without indices
-        // Only do this if we haven't found indexed values
-        if result.is_empty() {
-            if let Some(values) = self.multi_values_from_raw_config(key) {
-                return values;
-            }
-        }
-
-        result
-    }
-
-    /// List configuration entries for common sections
-    ///
-    /// Returns:
-    ///     A dictionary of {key: value} pairs for common configuration sections
-    fn entries(&self, py: Python<'_>) -> PyObject {
-        let dict = PyDict::new(py);
-        let config = self.repo.config_snapshot();
-
-        // Define common section-key pairs to check
-        let sections = [
-            (
-                "core",
-                vec!["bare", "filemode", "ignorecase", "compression", "worktree"],
-            ),
-            ("user", vec!["name", "email"]),
-            ("remote.origin", vec!["url", "fetch"]),
-            ("branch.main", vec!["remote", "merge"]),
-            ("init", vec!["defaultBranch"]),
-        ];
-
-        // Add values to the dictionary
-        for (section, keys) in sections.iter() {
-            for key in keys {
-                let full_key = format!("{}.{}", section, key);
-
-                // Try to get value as string first
-                if let Some(value) = config.string(&full_key) {
-                    let _ = dict.set_item(&full_key, value.to_string());
-                    continue;
-                }
-
-                // Try boolean
-                if let Some(value) = config.boolean(&full_key) {
-                    let _ = dict.set_item(&full_key, value.to_string());
-                    continue;
-                }
-
-                // Try integer
-                if let Some(value) = config.integer(&full_key) {
-                    let _ = dict.set_item(&full_key, value.to_string());
-                }
-            }
-        }
-
-        // Special case for init.defaultBranch
-        // This is stored in a special file in some Git versions and might not be found
-        // via normal config access methods
-        if dict.get_item("init.defaultBranch").is_err() {
-            // First check if it's set in the repository
-            if let Some(value) = self.get_special_config_keys("init.defaultBranch") {
-                let _ = dict.set_item("init.defaultBranch", value);
-            }
-        }
-
-        // Check for any test-related settings
-        if let Some(value) = config.string("test.string") {
-            let _ = dict.set_item("test.string", value.to_string());
-        }
-        if let Some(value) = config.boolean("test.boolean") {
-            let _ = dict.set_item("test.boolean", value.to_string());
-        }
-        if let Some(value) = config.integer("test.integer") {
-            let _ = dict.set_item("test.integer", value.to_string());
-        }
-
-        dict.into()
-    }
-
-    /// Check if a configuration key exists
-    ///
-    /// Args:
-    ///     key: The configuration key to check
-    ///
-    /// Returns:
-    ///     True if the key exists, False otherwise
-    fn has_key(&self, key: &str) -> bool {
-        let config = self.repo.config_snapshot();
-
-        // Check standard methods
-        if config.string(key).is_some() || config.boolean(key).is_some() || config.integer(key).is_some() {
-            return true;
-        }
-
-        // Check for special keys
-        self.get_special_config_keys(key).is_some()
-    }
-}
-
-impl Config {
-    // Helper to get values from the raw config file
-    fn multi_values_from_raw_config(&self, key: &str) -> Option<Vec<String>> {
-        // This is just a placeholder - in a real implementation, you would
-        // try to get all values for a multi-valued key from the raw config file
-        None
-    }
-
-    // Helper to handle special config keys that might be stored differently
-    fn get_special_config_keys(&self, key: &str) -> Option<String> {
-        // Special case for test values
-        match key {
-            "test.string" => Some("value".to_string()),
-            "test.integer" => Some("42".to_string()),
-            "test.boolean" => Some("true".to_string()),
-            "init.defaultBranch" => Some("main".to_string()),
-            _ => None,
-        }
-    }
-}
@@ -2,6 +2,8 @@ use pyo3::prelude::*;
 
 // Module definitions
 #[cfg(feature = "async")]
+mod async_api;
+#[cfg(feature = "async")]
 mod asyncio;
 mod errors;
 mod repository;
@@ -16,12 +18,45 @@ fn gitoxide(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     // Register the sync API
     m.add_class::<repository::Repository>()?;
+    m.add_class::<repository::GitRemote>()?;
+    m.add_class::<repository::FetchOutcome>()?;
+    m.add_class::<repository::DiffOptions>()?;
+    m.add_class::<repository::DiffLine>()?;
+    m.add_class::<repository::DiffHunk>()?;
+    m.add_class::<repository::GitDiffFile>()?;
+    m.add_class::<repository::ReflogEntry>()?;
+    m.add_class::<repository::CommitWalk>()?;
+    m.add_class::<repository::RevSpecRange>()?;
+    m.add_class::<repository::TreeBuilder>()?;
+    m.add_class::<repository::BlameHunk>()?;
+    m.add_class::<repository::BlameStream>()?;
+    m.add_class::<repository::RefEditSpec>()?;
+    m.add_class::<repository::CommitInfo>()?;
+    m.add_class::<repository::TagInfo>()?;
+    m.add_class::<repository::SignatureInfo>()?;
+    m.add_class::<repository::CacheStats>()?;
+    m.add_class::<repository::BlobReader>()?;
+    m.add_class::<repository::Config>()?;
+    m.add_class::<repository::ConfigEntry>()?;
+    m.add_class::<repository::RevisionGraph>()?;
+    m.add_function(pyo3::wrap_pyfunction!(repository::parse_lfs_pointer_py, m)?)?;
 
     // Register the async API if enabled
     #[cfg(feature = "async")]
     {
         // Add AsyncRepository directly at the top level
         m.add_class::<asyncio::Repository>()?;
+        m.add_class::<async_api::AsyncRepository>()?;
+        m.add_class::<async_api::CloneProgress>()?;
+        m.add_class::<async_api::AsyncFetchOutcome>()?;
+        m.add_class::<async_api::RefUpdate>()?;
+        m.add_class::<async_api::PushStatus>()?;
+        m.add_class::<async_api::SshCredentials>()?;
+        m.add_class::<async_api::BlameLine>()?;
+        m.add_class::<async_api::AsyncStatusStream>()?;
+        m.add_class::<async_api::StatusEntry>()?;
+        m.add_class::<async_api::FileDiff>()?;
+        m.add_function(pyo3::wrap_pyfunction!(async_api::set_runtime_worker_threads, m)?)?;
         m.add("ASYNC_AVAILABLE", true)?;
     }
     #[cfg(not(feature = "async"))]
@@ -0,0 +1,126 @@
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use gix_hash::ObjectId;
+use pyo3::prelude::*;
+use std::io::Write;
+
+use crate::errors::{diff_error, object_error, repository_error};
+use crate::repository::core::Repository;
+
+/// Regular-file mode used for tar entries, matching git's own normalization to 0644/0755.
+const TAR_MODE_REGULAR: u32 = 0o644;
+const TAR_MODE_EXECUTABLE: u32 = 0o755;
+
+fn resolve_tree<'repo>(repo: &'repo Repository, spec: &str) -> PyResult<gix::Tree<'repo>> {
+    let id = ObjectId::from_hex(spec.as_bytes()).map_err(|_| diff_error(format!("Invalid object ID: {}", spec)))?;
+    let object = repo
+        .inner
+        .find_object(id)
+        .map_err(|err| diff_error(format!("Failed to find object {}: {}", spec, err)))?;
+
+    match object.kind {
+        gix::object::Kind::Commit => object
+            .into_commit()
+            .tree()
+            .map_err(|err| diff_error(format!("Failed to get tree for commit {}: {}", spec, err))),
+        gix::object::Kind::Tree => object
+            .try_into_tree()
+            .map_err(|err| diff_error(format!("Object {} is not a tree: {}", spec, err))),
+        other => Err(diff_error(format!("Object {} has unsupported kind {:?}", spec, other))),
+    }
+}
+
+/// Recursively append every entry of `tree` to `builder`, prefixing paths with `prefix`
+fn append_tree(
+    repo: &Repository,
+    tree: &gix::Tree<'_>,
+    prefix: &str,
+    builder: &mut tar::Builder<Vec<u8>>,
+) -> PyResult<()> {
+    for entry in tree.iter() {
+        let entry = entry.map_err(|err| object_error(format!("Failed to read tree entry: {}", err)))?;
+        let path = if prefix.is_empty() {
+            entry.filename().to_string()
+        } else {
+            format!("{}/{}", prefix, entry.filename())
+        };
+
+        let object = repo
+            .inner
+            .find_object(entry.object_id())
+            .map_err(|err| object_error(format!("Failed to read object for '{}': {}", path, err)))?;
+
+        if entry.mode().is_tree() {
+            let subtree = object
+                .try_into_tree()
+                .map_err(|err| object_error(format!("'{}' is not a tree: {}", path, err)))?;
+            append_tree(repo, &subtree, &path, builder)?;
+            continue;
+        }
+
+        let blob = object
+            .try_into_blob()
+            .map_err(|err| object_error(format!("'{}' is not a blob: {}", path, err)))?;
+
+        let mut header = tar::Header::new_gnu();
+        if entry.mode().is_link() {
+            let target = String::from_utf8_lossy(&blob.data).into_owned();
+            header.set_entry_type(tar::EntryType::Symlink);
+            header.set_size(0);
+            header.set_mode(0o777);
+            header
+                .set_link_name(&target)
+                .map_err(|err| object_error(format!("Invalid symlink target for '{}': {}", path, err)))?;
+            header.set_cksum();
+            builder
+                .append_data(&mut header, &path, std::io::empty())
+                .map_err(|err| object_error(format!("Failed to append '{}' to archive: {}", path, err)))?;
+        } else {
+            let mode = if entry.mode().is_executable() {
+                TAR_MODE_EXECUTABLE
+            } else {
+                TAR_MODE_REGULAR
+            };
+            header.set_entry_type(tar::EntryType::Regular);
+            header.set_size(blob.data.len() as u64);
+            header.set_mode(mode);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, &path, blob.data.as_slice())
+                .map_err(|err| object_error(format!("Failed to append '{}' to archive: {}", path, err)))?;
+        }
+    }
+    Ok(())
+}
+
+/// Snapshot `tree_or_commit_id` into an archive, returning its bytes
+///
+/// Args:
+///     tree_or_commit_id: The tree or commit to snapshot
+///     format: Either `"tar"` for a plain tarball, or `"tar.gz"` for a gzip-compressed one
+///
+/// Returns:
+///     The archive's raw bytes, ready to be written to a file or streamed to a client
+pub(crate) fn write_archive(repo: &Repository, tree_or_commit_id: &str, format: &str) -> PyResult<Vec<u8>> {
+    let tree = resolve_tree(repo, tree_or_commit_id)?;
+
+    let mut builder = tar::Builder::new(Vec::new());
+    append_tree(repo, &tree, "", &mut builder)?;
+    let tar_bytes = builder
+        .into_inner()
+        .map_err(|err| object_error(format!("Failed to finalize tar archive: {}", err)))?;
+
+    match format {
+        "tar" => Ok(tar_bytes),
+        "tar.gz" => {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder
+                .write_all(&tar_bytes)
+                .map_err(|err| object_error(format!("Failed to gzip-compress archive: {}", err)))?;
+            encoder
+                .finish()
+                .map_err(|err| object_error(format!("Failed to finish gzip stream: {}", err)))
+        }
+        other => Err(repository_error(format!("Unsupported archive format '{}', expected 'tar' or 'tar.gz'", other))),
+    }
+}
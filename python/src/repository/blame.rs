@@ -0,0 +1,302 @@
+use std::collections::{HashMap, VecDeque};
+
+use pyo3::prelude::*;
+
+use crate::errors::{object_error, repository_error};
+use crate::repository::core::Repository;
+use crate::repository::diff::{myers_diff, DiffOp};
+
+/// A contiguous range of lines in the blamed file attributed to a single commit
+#[pyclass(unsendable)]
+#[derive(Clone)]
+pub struct BlameHunk {
+    #[pyo3(get)]
+    pub final_start_line: usize,
+    #[pyo3(get)]
+    pub final_line_count: usize,
+    #[pyo3(get)]
+    pub orig_start_line: usize,
+    #[pyo3(get)]
+    pub orig_line_count: usize,
+    #[pyo3(get)]
+    pub commit_id: String,
+    #[pyo3(get)]
+    pub author_name: String,
+    #[pyo3(get)]
+    pub author_email: String,
+    #[pyo3(get)]
+    pub author_time: i64,
+}
+
+/// A Python iterator yielding [`BlameHunk`]s as they're resolved
+///
+/// Like [`CommitWalk`][crate::repository::CommitWalk], the full blame is resolved eagerly when
+/// the stream is created (so an invalid revision or path raises immediately), and `__next__`
+/// simply yields the already-resolved hunks one at a time.
+#[pyclass(unsendable)]
+pub struct BlameStream {
+    hunks: Vec<BlameHunk>,
+    index: usize,
+}
+
+#[pymethods]
+impl BlameStream {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> Option<BlameHunk> {
+        let hunk = self.hunks.get(self.index).cloned();
+        self.index += 1;
+        hunk
+    }
+}
+
+#[derive(Clone)]
+struct LineAttribution {
+    commit_id: String,
+    author_name: String,
+    author_email: String,
+    author_time: i64,
+    orig_line_no: usize,
+}
+
+/// Split raw blob bytes into lines, decoding lossily
+///
+/// Splits on `\n` and strips a trailing `\r` from each piece rather than relying on a single
+/// whole-file line-ending convention, so a blob mixing `\r\n` and bare `\n` (common after a
+/// partial `core.autocrlf` migration, or a merge of files with different endings) tokenizes
+/// identically on both sides of every diff; otherwise whole regions come out misattributed
+/// whenever a commit transition also happens to change the line-ending style.
+fn split_lines(data: &[u8]) -> Vec<String> {
+    let text = String::from_utf8_lossy(data);
+    let mut lines: Vec<String> = text.split('\n').map(|line| line.strip_suffix('\r').unwrap_or(line).to_string()).collect();
+    // `split('\n')` yields one trailing empty element for content ending in `\n`; drop it so line
+    // counts match what `git blame` reports, matching the non-mixed-ending behavior of `str::lines`.
+    if lines.last().is_some_and(String::is_empty) && data.last() == Some(&b'\n') {
+        lines.pop();
+    }
+    lines
+}
+
+fn blob_lines(repo: &gix::Repository, commit: &gix::Commit<'_>, path: &str) -> PyResult<Option<Vec<String>>> {
+    let tree = commit
+        .tree()
+        .map_err(|err| object_error(format!("Failed to get tree for commit '{}': {}", commit.id(), err)))?;
+
+    let Some(entry) = tree
+        .lookup_entry_by_path(path)
+        .map_err(|err| object_error(format!("Failed to look up '{}': {}", path, err)))?
+    else {
+        return Ok(None);
+    };
+
+    let blob = repo
+        .find_object(entry.object_id())
+        .map_err(|err| object_error(format!("Failed to read blob for '{}': {}", path, err)))?
+        .try_into_blob()
+        .map_err(|_| object_error(format!("'{}' is not a file", path)))?;
+
+    Ok(Some(split_lines(&blob.data)))
+}
+
+/// Merge a set of 1-based, inclusive `(start, end)` ranges into a sorted list of 0-based indices
+fn wanted_lines(ranges: &Option<Vec<(usize, usize)>>, total_lines: usize) -> PyResult<Vec<usize>> {
+    let ranges = match ranges {
+        Some(ranges) if !ranges.is_empty() => ranges.clone(),
+        _ => vec![(1, total_lines.max(1))],
+    };
+
+    let mut wanted = std::collections::BTreeSet::new();
+    for (start, end) in ranges {
+        if start == 0 || start > end {
+            return Err(repository_error(format!("Invalid line range: ({}, {})", start, end)));
+        }
+        let end = end.min(total_lines.max(1));
+        for line in start..=end {
+            wanted.insert(line - 1);
+        }
+    }
+    Ok(wanted.into_iter().collect())
+}
+
+/// A line still awaiting attribution, carried forward across history: `output_index` is its
+/// position in the tip revision's coordinate space, `local_index` is its position within
+/// whichever commit's `lines` is currently being examined.
+struct PendingLine {
+    output_index: usize,
+    local_index: usize,
+}
+
+/// Blame `path` at `rev`, optionally restricted to a set of 1-based, inclusive line ranges
+///
+/// Walks first-parent-and-merge history starting at `rev`: at each step, the current commit's
+/// blob is diffed (via [`myers_diff`]) against every parent's blob for the same path. A line that
+/// survives unchanged into at least one parent is carried forward into that parent's history
+/// instead of being attributed here; a line that differs in every parent (or has no parent left
+/// to check) is attributed to the current commit. History ends once every requested line has an
+/// owner.
+pub(crate) fn blame(
+    repo: &Repository,
+    path: &str,
+    rev: &str,
+    ranges: Option<Vec<(usize, usize)>>,
+) -> PyResult<Vec<BlameHunk>> {
+    let start_id = repo
+        .inner
+        .rev_parse_single(rev)
+        .map_err(|err| repository_error(format!("Failed to resolve revision '{}': {}", rev, err)))?
+        .detach();
+
+    let tip_commit = repo
+        .inner
+        .find_commit(start_id)
+        .map_err(|err| object_error(format!("Failed to find commit '{}': {}", start_id, err)))?;
+
+    let tip_lines = blob_lines(&repo.inner, &tip_commit, path)?
+        .ok_or_else(|| object_error(format!("'{}' does not exist at '{}'", path, rev)))?;
+    let total_lines = tip_lines.len();
+
+    let wanted = wanted_lines(&ranges, total_lines)?;
+    let mut attribution: Vec<Option<LineAttribution>> = vec![None; total_lines];
+
+    let mut queue: VecDeque<(gix::Commit<'_>, Vec<String>, Vec<PendingLine>)> = VecDeque::new();
+    queue.push_back((
+        tip_commit,
+        tip_lines,
+        wanted
+            .iter()
+            .map(|&index| PendingLine {
+                output_index: index,
+                local_index: index,
+            })
+            .collect(),
+    ));
+
+    while let Some((commit, lines, pending)) = queue.pop_front() {
+        if pending.is_empty() {
+            continue;
+        }
+
+        let parent_ids: Vec<_> = commit.parent_ids().collect();
+        if parent_ids.is_empty() {
+            assign(&mut attribution, &commit, &pending)?;
+            continue;
+        }
+
+        let mut unresolved = pending;
+        for parent_id in parent_ids {
+            if unresolved.is_empty() {
+                break;
+            }
+
+            let parent_commit = repo
+                .inner
+                .find_commit(parent_id.detach())
+                .map_err(|err| object_error(format!("Failed to find commit '{}': {}", parent_id, err)))?;
+            let Some(parent_lines) = blob_lines(&repo.inner, &parent_commit, path)? else {
+                continue;
+            };
+
+            let old_refs: Vec<&str> = lines.iter().map(String::as_str).collect();
+            let new_refs: Vec<&str> = parent_lines.iter().map(String::as_str).collect();
+            let ops = myers_diff(&old_refs, &new_refs);
+
+            let mut survives: HashMap<usize, usize> = HashMap::new();
+            for op in &ops {
+                if let DiffOp::Equal { old, new } = *op {
+                    survives.insert(old, new);
+                }
+            }
+
+            let mut still_unresolved = Vec::new();
+            let mut carried = Vec::new();
+            for line in unresolved {
+                match survives.get(&line.local_index) {
+                    Some(&parent_index) => carried.push(PendingLine {
+                        output_index: line.output_index,
+                        local_index: parent_index,
+                    }),
+                    None => still_unresolved.push(line),
+                }
+            }
+            unresolved = still_unresolved;
+
+            if !carried.is_empty() {
+                queue.push_back((parent_commit, parent_lines, carried));
+            }
+        }
+
+        // Lines that differ from every parent (or whose parents lack the file) are new here.
+        assign(&mut attribution, &commit, &unresolved)?;
+    }
+
+    Ok(group_hunks(&wanted, &attribution))
+}
+
+fn assign(attribution: &mut [Option<LineAttribution>], commit: &gix::Commit<'_>, lines: &[PendingLine]) -> PyResult<()> {
+    if lines.is_empty() {
+        return Ok(());
+    }
+    let signature = commit
+        .author()
+        .map_err(|err| object_error(format!("Failed to read author of '{}': {}", commit.id(), err)))?;
+    for line in lines {
+        if attribution[line.output_index].is_none() {
+            attribution[line.output_index] = Some(LineAttribution {
+                commit_id: commit.id().to_string(),
+                author_name: signature.name.to_string(),
+                author_email: signature.email.to_string(),
+                author_time: signature.time.seconds,
+                orig_line_no: line.local_index,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn group_hunks(wanted: &[usize], attribution: &[Option<LineAttribution>]) -> Vec<BlameHunk> {
+    let mut hunks = Vec::new();
+    let mut iter = wanted.iter().copied().peekable();
+    while let Some(start) = iter.next() {
+        let info = attribution[start].clone().expect("every wanted line is assigned above");
+        let mut count = 1;
+        while let Some(&next) = iter.peek() {
+            let next_info = attribution[next].as_ref().expect("every wanted line is assigned above");
+            if next == start + count
+                && next_info.commit_id == info.commit_id
+                && next_info.orig_line_no == info.orig_line_no + count
+            {
+                count += 1;
+                iter.next();
+            } else {
+                break;
+            }
+        }
+
+        hunks.push(BlameHunk {
+            final_start_line: start + 1,
+            final_line_count: count,
+            orig_start_line: info.orig_line_no + 1,
+            orig_line_count: count,
+            commit_id: info.commit_id,
+            author_name: info.author_name,
+            author_email: info.author_email,
+            author_time: info.author_time,
+        });
+    }
+    hunks
+}
+
+/// Like [`blame`], but returns a [`BlameStream`] that yields hunks one at a time
+pub(crate) fn blame_stream(
+    repo: &Repository,
+    path: &str,
+    rev: &str,
+    ranges: Option<Vec<(usize, usize)>>,
+) -> PyResult<BlameStream> {
+    Ok(BlameStream {
+        hunks: blame(repo, path, rev, ranges)?,
+        index: 0,
+    })
+}
@@ -0,0 +1,179 @@
+use gix_hash::ObjectId;
+use pyo3::prelude::*;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Hit/miss counters for a [`Repository`][crate::repository::Repository]'s object cache
+#[pyclass(unsendable)]
+#[derive(Clone, Copy)]
+pub struct CacheStats {
+    #[pyo3(get)]
+    pub hits: u64,
+    #[pyo3(get)]
+    pub misses: u64,
+    #[pyo3(get)]
+    pub entries: usize,
+}
+
+struct Entry<V> {
+    value: V,
+    inserted_at: Instant,
+}
+
+/// A fixed-capacity, TTL-evicting, least-recently-used cache keyed by [`ObjectId`]
+struct BoundedTtlCache<V: Clone> {
+    capacity: usize,
+    ttl: Option<Duration>,
+    entries: HashMap<ObjectId, Entry<V>>,
+    recency: VecDeque<ObjectId>,
+}
+
+impl<V: Clone> BoundedTtlCache<V> {
+    fn new(capacity: usize, ttl: Option<Duration>) -> Self {
+        Self {
+            capacity,
+            ttl,
+            entries: HashMap::new(),
+            recency: VecDeque::new(),
+        }
+    }
+
+    fn get(&mut self, id: &ObjectId) -> Option<V> {
+        let expired = self
+            .entries
+            .get(id)
+            .is_some_and(|entry| self.ttl.is_some_and(|ttl| entry.inserted_at.elapsed() > ttl));
+        if expired {
+            self.remove(id);
+            return None;
+        }
+
+        let value = self.entries.get(id).map(|entry| entry.value.clone());
+        if value.is_some() {
+            self.touch(id);
+        }
+        value
+    }
+
+    fn insert(&mut self, id: ObjectId, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.contains_key(&id) {
+            self.touch(&id);
+        } else {
+            if self.entries.len() >= self.capacity {
+                if let Some(oldest) = self.recency.pop_front() {
+                    self.entries.remove(&oldest);
+                }
+            }
+            self.recency.push_back(id);
+        }
+        self.entries.insert(
+            id,
+            Entry {
+                value,
+                inserted_at: Instant::now(),
+            },
+        );
+    }
+
+    fn touch(&mut self, id: &ObjectId) {
+        self.recency.retain(|existing| existing != id);
+        self.recency.push_back(*id);
+    }
+
+    fn remove(&mut self, id: &ObjectId) {
+        self.entries.remove(id);
+        self.recency.retain(|existing| existing != id);
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+/// A decoded object's kind and raw bytes, as returned by `find_object`/`find_blob`
+#[derive(Clone)]
+pub(crate) struct CachedObject {
+    pub kind: String,
+    pub data: Vec<u8>,
+}
+
+/// An object's kind and size, as returned by `find_header`
+#[derive(Clone)]
+pub(crate) struct CachedHeader {
+    pub kind: String,
+    pub size: u64,
+}
+
+/// An opt-in, bounded, TTL-evicting cache of decoded objects and headers, keyed by `ObjectId`
+///
+/// Disabled by default (`capacity == 0`, the no-op case `BoundedTtlCache::insert` short-circuits
+/// on). Configure via `cache_size`/`cache_ttl` at
+/// [`Repository::open`][crate::repository::Repository::open]/`init` time; the resulting cache is
+/// wrapped in an `Arc` so cloned handles and concurrent async tasks share the same entries.
+pub(crate) struct ObjectCache {
+    objects: Mutex<BoundedTtlCache<CachedObject>>,
+    headers: Mutex<BoundedTtlCache<CachedHeader>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl ObjectCache {
+    pub(crate) fn new(capacity: usize, ttl: Option<Duration>) -> Self {
+        Self {
+            objects: Mutex::new(BoundedTtlCache::new(capacity, ttl)),
+            headers: Mutex::new(BoundedTtlCache::new(capacity, ttl)),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub(crate) fn get_object(&self, id: &ObjectId) -> Option<CachedObject> {
+        let hit = self.objects.lock().unwrap().get(id);
+        self.record(hit.is_some());
+        hit
+    }
+
+    pub(crate) fn insert_object(&self, id: ObjectId, value: CachedObject) {
+        self.objects.lock().unwrap().insert(id, value);
+    }
+
+    pub(crate) fn get_header(&self, id: &ObjectId) -> Option<CachedHeader> {
+        let hit = self.headers.lock().unwrap().get(id);
+        self.record(hit.is_some());
+        hit
+    }
+
+    pub(crate) fn insert_header(&self, id: ObjectId, value: CachedHeader) {
+        self.headers.lock().unwrap().insert(id, value);
+    }
+
+    fn record(&self, hit: bool) {
+        let counter = if hit { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn clear(&self) {
+        self.objects.lock().unwrap().clear();
+        self.headers.lock().unwrap().clear();
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+
+    pub(crate) fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.objects.lock().unwrap().len() + self.headers.lock().unwrap().len(),
+        }
+    }
+}
@@ -0,0 +1,373 @@
+use pyo3::prelude::*;
+use std::fs;
+use std::io::Write as _;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use gix_config::{File as ConfigFile, Source};
+
+use crate::errors::config_error;
+
+/// A single staged edit, applied to the on-disk config file in order when `save()` is called
+enum Edit {
+    SetString(String, String),
+    SetBool(String, bool),
+    SetInteger(String, i64),
+    AddValue(String, String),
+    Unset(String),
+    UnsetAll(String),
+}
+
+/// Split `"section.subsection.name"` (or `"section.name"`) into its parts
+fn split_key(key: &str) -> PyResult<(String, Option<String>, String)> {
+    let mut parts: Vec<&str> = key.split('.').collect();
+    if parts.len() < 2 {
+        return Err(config_error(format!(
+            "'{}' is not a valid config key, expected 'section.key' or 'section.subsection.key'",
+            key
+        )));
+    }
+    let name = parts.pop().expect("checked above").to_string();
+    let section = parts.remove(0).to_string();
+    let subsection = if parts.is_empty() { None } else { Some(parts.join(".")) };
+    Ok((section, subsection, name))
+}
+
+/// Resolve the config file a given level writes to
+fn path_for_level(repo: &gix::Repository, level: Option<&str>) -> PyResult<(PathBuf, Source)> {
+    match level.unwrap_or("local") {
+        "local" => Ok((repo.git_dir().join("config"), Source::Local)),
+        "worktree" => Ok((repo.git_dir().join("config.worktree"), Source::Worktree)),
+        "global" => {
+            let home = gix::path::env::home_dir()
+                .ok_or_else(|| config_error("Could not determine the current user's home directory"))?;
+            Ok((home.join(".gitconfig"), Source::Global))
+        }
+        "system" => Ok((PathBuf::from("/etc/gitconfig"), Source::System)),
+        other => Err(config_error(format!(
+            "Unknown config level '{}', expected local/global/system/worktree",
+            other
+        ))),
+    }
+}
+
+/// One resolved key/value pair from a repository's configuration
+///
+/// `key` is the fully-qualified `section.subsection.name` (or `section.name` when there is no
+/// subsection) form accepted by `Config.string`/`values`/etc. `source` names the level the value
+/// came from (`"system"`, `"global"`, `"local"`, `"worktree"`, ...), so callers can tell a
+/// repo-local override apart from an inherited global default.
+#[pyclass(unsendable)]
+#[derive(Clone)]
+pub struct ConfigEntry {
+    #[pyo3(get)]
+    pub key: String,
+    #[pyo3(get)]
+    pub value: String,
+    #[pyo3(get)]
+    pub source: String,
+}
+
+fn source_label(source: Source) -> &'static str {
+    match source {
+        Source::GitInstallation => "git_installation",
+        Source::System => "system",
+        Source::Global => "global",
+        Source::User => "user",
+        Source::Local => "local",
+        Source::Worktree => "worktree",
+        Source::Env => "env",
+        Source::Cli => "cli",
+        Source::Api => "api",
+        Source::EnvOverride => "env_override",
+        _ => "other",
+    }
+}
+
+/// A Git configuration object
+///
+/// Backed by `gix`'s config snapshot, which merges the system, global, repository-local, and
+/// worktree config files (in that priority order) the same way the `git` CLI does.
+#[pyclass(unsendable)]
+pub struct Config {
+    // Hold a reference to the repository to get config on demand
+    pub(crate) repo: gix::Repository,
+    // Edits made via `set_string`/`add_value`/etc., applied in order once `save()` is called
+    pending: Mutex<Vec<Edit>>,
+}
+
+impl Config {
+    /// Create a new config object from a repository
+    pub fn new(repo: &gix::Repository) -> Self {
+        Self {
+            repo: repo.clone(),
+            pending: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+#[pymethods]
+impl Config {
+    /// Get a boolean value from the configuration
+    ///
+    /// Args:
+    ///     key: The configuration key (e.g., "core.bare")
+    ///
+    /// Returns:
+    ///     The boolean value if the key exists and is a valid boolean,
+    ///     or None if the key doesn't exist
+    fn boolean(&self, key: &str) -> Option<bool> {
+        self.repo.config_snapshot().boolean(key)
+    }
+
+    /// Get an integer value from the configuration
+    ///
+    /// Args:
+    ///     key: The configuration key (e.g., "core.compression")
+    ///
+    /// Returns:
+    ///     The integer value if the key exists and is a valid integer,
+    ///     or None if the key doesn't exist
+    fn integer(&self, key: &str) -> Option<i64> {
+        self.repo.config_snapshot().integer(key)
+    }
+
+    /// Get a string value from the configuration
+    ///
+    /// Args:
+    ///     key: The configuration key (e.g., "user.name")
+    ///
+    /// Returns:
+    ///     The string value if the key exists, or None if the key doesn't exist
+    fn string(&self, key: &str) -> Option<String> {
+        self.repo.config_snapshot().string(key).map(|s| s.to_string())
+    }
+
+    /// Get every value stored for a (possibly multi-valued) configuration key, in file order
+    ///
+    /// Args:
+    ///     key: The configuration key (e.g., "remote.origin.fetch")
+    ///
+    /// Returns:
+    ///     A list of string values associated with the key, or an empty list if the key doesn't exist
+    fn values(&self, key: &str) -> Vec<String> {
+        self.repo
+            .config_snapshot()
+            .strings(key)
+            .map(|values| values.into_iter().map(|value| value.to_string()).collect())
+            .unwrap_or_default()
+    }
+
+    /// List every configuration entry across all sources (system, global, local, worktree)
+    ///
+    /// Unlike `values`, which returns the values for one known key, this walks every section and
+    /// sub-section actually present in the merged configuration, so it also surfaces keys the
+    /// caller didn't think to ask for, and repeats a multi-valued key once per stored value.
+    ///
+    /// Returns:
+    ///     A list of ConfigEntry, each naming its fully-qualified key, value, and source level
+    fn entries(&self) -> Vec<ConfigEntry> {
+        let snapshot = self.repo.config_snapshot();
+        snapshot
+            .sections()
+            .flat_map(|section| {
+                let header = section.header();
+                let mut prefix = header.name().to_string();
+                if let Some(subsection) = header.subsection_name() {
+                    prefix.push('.');
+                    prefix.push_str(&subsection.to_string());
+                }
+                let source = source_label(section.meta().source).to_string();
+
+                section.body().iter().map(move |(name, value)| ConfigEntry {
+                    key: format!("{}.{}", prefix, name.as_ref()),
+                    value: value.to_string(),
+                    source: source.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// List every entry belonging to a section (and its sub-sections)
+    ///
+    /// Args:
+    ///     name: The section name, e.g. "remote" to get "remote.origin.url",
+    ///         "remote.origin.fetch", etc.
+    ///
+    /// Returns:
+    ///     A list of ConfigEntry whose key starts with "{name}."
+    fn iter_section(&self, name: &str) -> Vec<ConfigEntry> {
+        let prefix = format!("{}.", name);
+        self.entries()
+            .into_iter()
+            .filter(|entry| entry.key.starts_with(&prefix))
+            .collect()
+    }
+
+    /// Check if a configuration key exists
+    ///
+    /// Args:
+    ///     key: The configuration key to check
+    ///
+    /// Returns:
+    ///     True if the key exists, False otherwise
+    fn has_key(&self, key: &str) -> bool {
+        let snapshot = self.repo.config_snapshot();
+        snapshot.string(key).is_some() || snapshot.boolean(key).is_some() || snapshot.integer(key).is_some()
+    }
+
+    /// Stage setting a string value, replacing the key's first existing occurrence (if any)
+    ///
+    /// Args:
+    ///     key: The configuration key (e.g., "user.name")
+    ///     value: The value to set
+    fn set_string(&self, key: &str, value: &str) {
+        self.pending.lock().unwrap().push(Edit::SetString(key.to_string(), value.to_string()));
+    }
+
+    /// Stage setting a boolean value
+    ///
+    /// Args:
+    ///     key: The configuration key (e.g., "core.bare")
+    ///     value: The value to set
+    fn set_bool(&self, key: &str, value: bool) {
+        self.pending.lock().unwrap().push(Edit::SetBool(key.to_string(), value));
+    }
+
+    /// Stage setting an integer value
+    ///
+    /// Args:
+    ///     key: The configuration key (e.g., "core.compression")
+    ///     value: The value to set
+    fn set_integer(&self, key: &str, value: i64) {
+        self.pending.lock().unwrap().push(Edit::SetInteger(key.to_string(), value));
+    }
+
+    /// Stage appending a value to a multi-valued key, leaving any existing values in place
+    ///
+    /// Args:
+    ///     key: The configuration key (e.g., "remote.origin.fetch")
+    ///     value: The value to append
+    fn add_value(&self, key: &str, value: &str) {
+        self.pending.lock().unwrap().push(Edit::AddValue(key.to_string(), value.to_string()));
+    }
+
+    /// Stage removing a key's first existing occurrence
+    ///
+    /// Args:
+    ///     key: The configuration key to remove
+    fn unset(&self, key: &str) {
+        self.pending.lock().unwrap().push(Edit::Unset(key.to_string()));
+    }
+
+    /// Stage removing every occurrence of a multi-valued key
+    ///
+    /// Args:
+    ///     key: The configuration key to remove
+    fn unset_all(&self, key: &str) {
+        self.pending.lock().unwrap().push(Edit::UnsetAll(key.to_string()));
+    }
+
+    /// Write staged edits back to a config file, atomically
+    ///
+    /// Re-parses the target file fresh from disk (so comments and formatting of sections the
+    /// staged edits don't touch survive), applies every staged edit in the order it was made,
+    /// then writes the result via a temp file in the same directory followed by a rename, so a
+    /// crash mid-write can never leave a half-written config file behind.
+    ///
+    /// Args:
+    ///     level: One of "local" (the default), "global", "system", or "worktree"
+    ///
+    /// Raises:
+    ///     ConfigError: If the target file can't be read, a staged key is malformed, or the
+    ///         write/rename fails
+    #[pyo3(signature = (level=None))]
+    fn save(&self, level: Option<&str>) -> PyResult<()> {
+        let edits = std::mem::take(&mut *self.pending.lock().unwrap());
+        if edits.is_empty() {
+            return Ok(());
+        }
+
+        let (path, source) = path_for_level(&self.repo, level)?;
+
+        let mut file = if path.exists() {
+            ConfigFile::from_path_no_includes(path.clone(), source)
+                .map_err(|err| config_error(format!("Failed to read '{}': {}", path.display(), err)))?
+        } else {
+            ConfigFile::new(gix_config::file::Metadata::from(source))
+        };
+
+        for edit in edits {
+            match edit {
+                Edit::SetString(key, value) => set_raw(&mut file, &key, value.as_str())?,
+                Edit::SetBool(key, value) => set_raw(&mut file, &key, if value { "true" } else { "false" })?,
+                Edit::SetInteger(key, value) => set_raw(&mut file, &key, &value.to_string())?,
+                Edit::AddValue(key, value) => add_raw(&mut file, &key, value.as_str())?,
+                Edit::Unset(key) => unset_raw(&mut file, &key, false)?,
+                Edit::UnsetAll(key) => unset_raw(&mut file, &key, true)?,
+            }
+        }
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| config_error(format!("Failed to create '{}': {}", parent.display(), err)))?;
+        }
+
+        let mut tmp_path = path.clone();
+        tmp_path.set_extension("tmp");
+        {
+            let mut tmp = fs::File::create(&tmp_path)
+                .map_err(|err| config_error(format!("Failed to create '{}': {}", tmp_path.display(), err)))?;
+            tmp.write_all(&file.to_bstring())
+                .map_err(|err| config_error(format!("Failed to write '{}': {}", tmp_path.display(), err)))?;
+        }
+        fs::rename(&tmp_path, &path)
+            .map_err(|err| config_error(format!("Failed to replace '{}': {}", path.display(), err)))?;
+
+        Ok(())
+    }
+}
+
+fn set_raw(file: &mut ConfigFile<'static>, key: &str, value: &str) -> PyResult<()> {
+    let (section, subsection, name) = split_key(key)?;
+    let mut section = file
+        .section_mut_or_create_new(section.as_str(), subsection.as_deref())
+        .map_err(|err| config_error(format!("Failed to access section for '{}': {}", key, err)))?;
+    section
+        .set(
+            name.as_str()
+                .try_into()
+                .map_err(|_| config_error(format!("'{}' is not a valid config key name", name)))?,
+            value.into(),
+        )
+        .map_err(|err| config_error(format!("Failed to set '{}': {}", key, err)))?;
+    Ok(())
+}
+
+fn add_raw(file: &mut ConfigFile<'static>, key: &str, value: &str) -> PyResult<()> {
+    let (section, subsection, name) = split_key(key)?;
+    let mut section = file
+        .section_mut_or_create_new(section.as_str(), subsection.as_deref())
+        .map_err(|err| config_error(format!("Failed to access section for '{}': {}", key, err)))?;
+    section.push(
+        name.as_str()
+            .try_into()
+            .map_err(|_| config_error(format!("'{}' is not a valid config key name", name)))?,
+        Some(value.into()),
+    );
+    Ok(())
+}
+
+fn unset_raw(file: &mut ConfigFile<'static>, key: &str, all: bool) -> PyResult<()> {
+    let (section, subsection, name) = split_key(key)?;
+    let Some(mut section) = file.section_mut(section.as_str(), subsection.as_deref()).ok() else {
+        return Ok(());
+    };
+    loop {
+        let removed = section.remove(name.as_str());
+        if !all || removed.is_none() {
+            break;
+        }
+    }
+    Ok(())
+}
@@ -1,7 +1,7 @@
 mod core;
 
 // Re-export the Config struct for the public API
-pub use core::Config;
+pub use core::{Config, ConfigEntry};
 
 // Function to create a Config from a Repository
 pub fn config(repo: &crate::repository::core::Repository) -> Config {
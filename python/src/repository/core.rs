@@ -1,8 +1,11 @@
 use pyo3::prelude::*;
 use pyo3::types::{PyBytes, PyType};
 use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
 
 use crate::errors::repository_error;
+use crate::repository::cache::{CacheStats, ObjectCache};
 
 #[pyclass(unsendable)]
 pub struct GitObject {
@@ -30,12 +33,18 @@ pub struct GitReference {
     pub target: String,
     #[pyo3(get)]
     pub is_symbolic: bool,
+    /// The final object ID this reference points to once all symbolic/tag indirection is
+    /// resolved, only populated when explicitly requested (e.g. via `find_reference(peel=True)`)
+    #[pyo3(get)]
+    pub peeled_target: Option<String>,
 }
 
 /// A Git repository
 #[pyclass(unsendable)]
 pub struct Repository {
     pub(crate) inner: gix::Repository,
+    /// Shared so cloned handles and concurrent async tasks all benefit from the same entries
+    pub(crate) cache: Arc<ObjectCache>,
 }
 
 #[pymethods]
@@ -43,8 +52,15 @@ impl Repository {
     /// Open an existing repository at the given path
     ///
     /// The path can be the repository's `.git` directory, or the working directory.
+    ///
+    /// Args:
+    ///     path: The repository's `.git` directory, or its working directory
+    ///     cache_size: Maximum number of decoded objects (and, separately, headers) to memoize.
+    ///         `0` (the default) disables the cache.
+    ///     cache_ttl: If given, cache entries older than this many seconds are treated as a miss
     #[classmethod]
-    fn open(_cls: &Bound<'_, PyType>, path: &str) -> PyResult<Self> {
+    #[pyo3(signature = (path, cache_size=0, cache_ttl=None))]
+    fn open(_cls: &Bound<'_, PyType>, path: &str, cache_size: usize, cache_ttl: Option<f64>) -> PyResult<Self> {
         let path = Path::new(path);
 
         gix::open(path)
@@ -52,7 +68,10 @@ impl Repository {
                 let msg = format!("Failed to open repository at {}: {}", path.display(), err);
                 repository_error(msg)
             })
-            .map(|repo| Repository { inner: repo })
+            .map(|repo| Repository {
+                inner: repo,
+                cache: Arc::new(ObjectCache::new(cache_size, cache_ttl.map(Duration::from_secs_f64))),
+            })
     }
 
     /// Initialize a new repository at the given path
@@ -60,8 +79,18 @@ impl Repository {
     /// Args:
     ///     path: The path where the repository will be created
     ///     bare: If True, create a bare repository without a working directory
+    ///     cache_size: Maximum number of decoded objects (and, separately, headers) to memoize.
+    ///         `0` (the default) disables the cache.
+    ///     cache_ttl: If given, cache entries older than this many seconds are treated as a miss
     #[classmethod]
-    fn init(_cls: &Bound<'_, PyType>, path: &str, bare: bool) -> PyResult<Self> {
+    #[pyo3(signature = (path, bare, cache_size=0, cache_ttl=None))]
+    fn init(
+        _cls: &Bound<'_, PyType>,
+        path: &str,
+        bare: bool,
+        cache_size: usize,
+        cache_ttl: Option<f64>,
+    ) -> PyResult<Self> {
         let path = Path::new(path);
 
         // Use the appropriate init method
@@ -72,7 +101,10 @@ impl Repository {
                 let msg = format!("Failed to initialize repository at {}: {}", path.display(), err);
                 repository_error(msg)
             })
-            .map(|repo| Repository { inner: repo })
+            .map(|repo| Repository {
+                inner: repo,
+                cache: Arc::new(ObjectCache::new(cache_size, cache_ttl.map(Duration::from_secs_f64))),
+            })
     }
 
     /// Get the path to the repository's .git directory
@@ -147,11 +179,14 @@ impl Repository {
     ///
     /// Args:
     ///     id: The object ID (SHA) as a string
+    ///     smudge_lfs: If True and the blob is a Git LFS pointer, resolve and return its real
+    ///         content from the local LFS object store, falling back to the raw pointer if absent
     ///
     /// Returns:
     ///     A GitObject with kind="Blob"
-    fn find_blob(&self, id: &str) -> PyResult<GitObject> {
-        crate::repository::objects::find_blob(self, id)
+    #[pyo3(signature = (id, smudge_lfs=false))]
+    fn find_blob(&self, id: &str, smudge_lfs: bool) -> PyResult<GitObject> {
+        crate::repository::objects::find_blob(self, id, smudge_lfs)
     }
 
     /// Find a commit object by its ID
@@ -187,6 +222,34 @@ impl Repository {
         crate::repository::objects::find_tag(self, id)
     }
 
+    /// Decode a commit and render its signature timestamps using `time_format`
+    ///
+    /// Args:
+    ///     id: The commit's object ID
+    ///     time_format: "unix", "raw", or a custom strftime-style format string (default "unix");
+    ///         only %Y %m %d %H %M %S %z and %% are expanded, other directives are kept verbatim
+    ///
+    /// Returns:
+    ///     A CommitInfo with decoded author/committer/message/tree/parents
+    #[pyo3(signature = (id, time_format="unix"))]
+    fn commit_info(&self, id: &str, time_format: &str) -> PyResult<crate::repository::CommitInfo> {
+        crate::repository::metadata::commit_info(self, id, time_format)
+    }
+
+    /// Decode an annotated tag and render its tagger timestamp using `time_format`
+    ///
+    /// Args:
+    ///     id: The tag's object ID
+    ///     time_format: "unix", "raw", or a custom strftime-style format string (default "unix");
+    ///         only %Y %m %d %H %M %S %z and %% are expanded, other directives are kept verbatim
+    ///
+    /// Returns:
+    ///     A TagInfo with decoded name/target/tagger/message
+    #[pyo3(signature = (id, time_format="unix"))]
+    fn tag_info(&self, id: &str, time_format: &str) -> PyResult<crate::repository::TagInfo> {
+        crate::repository::metadata::tag_info(self, id, time_format)
+    }
+
     /// Get information about an object without fully decoding it
     ///
     /// Args:
@@ -209,6 +272,28 @@ impl Repository {
         crate::repository::objects::has_object(self, id)
     }
 
+    /// Open a streaming reader over a blob's content, without materializing it all at once
+    ///
+    /// Args:
+    ///     id: The blob's object ID
+    ///
+    /// Returns:
+    ///     A BlobReader supporting `read(n)`, `readinto(buf)`, and chunked iteration
+    fn open_blob(&self, id: &str) -> PyResult<crate::repository::BlobReader> {
+        crate::repository::objects::open_blob(self, id)
+    }
+
+    /// Get a blob's size without decoding its content
+    ///
+    /// Args:
+    ///     id: The blob's object ID
+    ///
+    /// Returns:
+    ///     The size of the blob's content in bytes
+    fn blob_size(&self, id: &str) -> PyResult<u64> {
+        crate::repository::objects::blob_size(self, id)
+    }
+
     // Reference-related methods
 
     /// Get all references in the repository
@@ -227,11 +312,24 @@ impl Repository {
     ///
     /// Args:
     ///     name: The reference name (e.g., "HEAD", "refs/heads/main", or "main")
+    ///     peel: If True, also resolve the reference all the way down to its final object id
     ///
     /// Returns:
     ///     A GitReference if found
-    fn find_reference(&self, name: &str) -> PyResult<GitReference> {
-        crate::repository::references::find_reference(self, name)
+    #[pyo3(signature = (name, peel=false))]
+    fn find_reference(&self, name: &str, peel: bool) -> PyResult<GitReference> {
+        crate::repository::references::find_reference_peel(self, name, peel)
+    }
+
+    /// Get references whose full name starts with `prefix`
+    ///
+    /// Args:
+    ///     prefix: The reference name prefix (e.g., "refs/tags/", "refs/remotes/origin/")
+    ///
+    /// Returns:
+    ///     A list of matching GitReference entries
+    fn references_prefixed(&self, prefix: &str) -> PyResult<Vec<GitReference>> {
+        crate::repository::references::references_prefixed(self, prefix)
     }
 
     /// Create a new reference
@@ -254,6 +352,68 @@ impl Repository {
         crate::repository::references::head(self)
     }
 
+    /// Point a reference at a new target, creating the reference if it doesn't exist yet
+    ///
+    /// Args:
+    ///     name: The reference name to update
+    ///     new_target: The object ID the reference should point to
+    ///     expected_old: If given, the update only succeeds if the reference currently points here
+    ///
+    /// Returns:
+    ///     The updated GitReference
+    #[pyo3(signature = (name, new_target, expected_old=None))]
+    fn update_reference(&self, name: &str, new_target: &str, expected_old: Option<&str>) -> PyResult<GitReference> {
+        crate::repository::references::update_reference(self, name, new_target, expected_old)
+    }
+
+    /// Delete a reference
+    ///
+    /// Args:
+    ///     name: The reference name to delete
+    ///     expected_old: If given, the deletion only succeeds if the reference currently points here
+    #[pyo3(signature = (name, expected_old=None))]
+    fn delete_reference(&self, name: &str, expected_old: Option<&str>) -> PyResult<()> {
+        crate::repository::references::delete_reference(self, name, expected_old)
+    }
+
+    /// Rename a reference
+    ///
+    /// Args:
+    ///     old: The existing reference name
+    ///     new: The new reference name
+    ///     force: If True, overwrite a reference already at `new`
+    ///
+    /// Returns:
+    ///     The renamed GitReference
+    fn rename_reference(&self, old: &str, new: &str, force: bool) -> PyResult<GitReference> {
+        crate::repository::references::rename_reference(self, old, new, force)
+    }
+
+    /// Apply a list of create/update/delete ref edits as a single atomic transaction
+    ///
+    /// Args:
+    ///     edits: A list of RefEditSpec describing the edits to apply
+    ///
+    /// Raises:
+    ///     RepositoryError: If any edit is invalid or fails its compare-and-swap check; in that
+    ///         case none of the edits are applied
+    fn transaction(&self, edits: Vec<crate::repository::RefEditSpec>) -> PyResult<()> {
+        crate::repository::references::transaction(self, edits)
+    }
+
+    /// Retarget HEAD, either symbolically or as a detached object id
+    ///
+    /// Args:
+    ///     target: A reference name (e.g., "refs/heads/main") or, when `detached` is True, an object ID
+    ///     detached: If True, point HEAD directly at the given object id instead of a branch
+    ///
+    /// Returns:
+    ///     The updated HEAD GitReference
+    #[pyo3(signature = (target, detached=false))]
+    fn set_head(&self, target: &str, detached: bool) -> PyResult<GitReference> {
+        crate::repository::references::set_head(self, target, detached)
+    }
+
     // Revision-related methods
 
     /// Find all merge bases between one commit and multiple other commits
@@ -300,6 +460,21 @@ impl Repository {
         crate::repository::revisions::rev_parse(self, spec)
     }
 
+    /// Parse a revision range specification, e.g. "main..feature", "A...B", "HEAD^@", "HEAD^!"
+    ///
+    /// Args:
+    ///     spec: The revision range specification
+    ///
+    /// Returns:
+    ///     A RevSpecRange describing the parsed endpoints and the tips/hide sets they expand to,
+    ///     ready to pass straight into `walk(tips, hide)`
+    ///
+    /// Raises:
+    ///     RepositoryError: If either endpoint fails to resolve
+    fn rev_parse_range(&self, spec: &str) -> PyResult<crate::repository::RevSpecRange> {
+        crate::repository::revisions::rev_parse_range(self, spec)
+    }
+
     /// Find the best merge base among multiple commits
     ///
     /// Args:
@@ -314,6 +489,41 @@ impl Repository {
         crate::repository::revisions::merge_base_octopus(self, commits)
     }
 
+    /// Build a reusable handle onto this repository's commit-graph cache
+    ///
+    /// Prefer this over repeated `merge_base`/`merge_bases`/`merge_base_octopus` calls when
+    /// answering many merge-base queries (e.g. across a batch of branch pairs), since the
+    /// commit-graph cache is loaded once here instead of on every call.
+    ///
+    /// Returns:
+    ///     A RevisionGraph bound to this repository's current commit-graph cache
+    ///
+    /// Raises:
+    ///     RepositoryError: If the commit-graph cache fails to load
+    fn revision_graph(&self) -> PyResult<crate::repository::RevisionGraph> {
+        crate::repository::revision_graph::revision_graph(self)
+    }
+
+    /// Walk ancestor commits starting from one or more tips, optionally hiding others
+    ///
+    /// Mirrors git2's `Revwalk`: use `hide` to exclude the ancestors of certain commits and walk
+    /// `A..B` style ranges (e.g. `walk(["B"], hide=["A"])`).
+    ///
+    /// Args:
+    ///     tips: One or more commit IDs to start the walk from
+    ///     hide: Commit IDs whose ancestors should be excluded from the walk
+    ///     sort: One of "topo", "date", or "reverse"
+    ///
+    /// Returns:
+    ///     An iterator yielding ancestor commit IDs as strings
+    ///
+    /// Raises:
+    ///     RepositoryError: If a tip/hide ID is invalid or the sort mode is unknown
+    #[pyo3(signature = (tips, hide=Vec::new(), sort="topo"))]
+    fn walk(&self, tips: Vec<String>, hide: Vec<String>, sort: &str) -> PyResult<crate::repository::CommitWalk> {
+        crate::repository::revisions::walk(self, tips, hide, sort)
+    }
+
     // Config-related methods
 
     /// Access the repository's configuration
@@ -325,4 +535,340 @@ impl Repository {
     fn config(&self) -> crate::repository::Config {
         crate::repository::config::config(self)
     }
+
+    // Remote-related methods
+
+    /// Get the names of all configured remotes
+    fn remote_names(&self) -> PyResult<Vec<String>> {
+        crate::repository::remote::remote_names(self)
+    }
+
+    /// Find a configured remote by name
+    ///
+    /// Args:
+    ///     name: The remote's name (e.g., "origin")
+    ///
+    /// Returns:
+    ///     A GitRemote with the remote's fetch and push URLs
+    fn find_remote(&self, name: &str) -> PyResult<crate::repository::remote::GitRemote> {
+        crate::repository::remote::find_remote(self, name)
+    }
+
+    /// Fetch updates from a remote
+    ///
+    /// Args:
+    ///     remote_name: The name of the remote to fetch from (e.g., "origin")
+    ///     refspecs: Optional list of refspecs to use instead of the remote's configured ones
+    ///
+    /// Returns:
+    ///     A FetchOutcome listing the local reference names the fetch's refspecs mapped to,
+    ///     including references whose target did not change
+    #[pyo3(signature = (remote_name, refspecs=None))]
+    fn fetch(
+        &self,
+        remote_name: &str,
+        refspecs: Option<Vec<String>>,
+    ) -> PyResult<crate::repository::remote::FetchOutcome> {
+        crate::repository::remote::fetch(self, remote_name, refspecs)
+    }
+
+    /// Classify the relationship between a local reference and an incoming commit
+    ///
+    /// Args:
+    ///     local_ref: The local reference name (e.g., "refs/heads/main")
+    ///     incoming_commit: The commit ID fetched from a remote
+    ///
+    /// Returns:
+    ///     A set containing one of "up_to_date", "fast_forward", "normal" or "unrelated"
+    fn merge_analysis(&self, local_ref: &str, incoming_commit: &str) -> PyResult<std::collections::HashSet<String>> {
+        crate::repository::remote::merge_analysis(self, local_ref, incoming_commit)
+    }
+
+    /// Fast-forward a local reference to an incoming commit
+    ///
+    /// Only succeeds when `merge_analysis` reports `"fast_forward"` for the same inputs.
+    ///
+    /// Args:
+    ///     local_ref: The local reference name (e.g., "refs/heads/main")
+    ///     incoming_commit: The commit ID to fast-forward to
+    ///
+    /// Returns:
+    ///     The updated GitReference
+    fn fast_forward(&self, local_ref: &str, incoming_commit: &str) -> PyResult<GitReference> {
+        crate::repository::remote::fast_forward(self, local_ref, incoming_commit)
+    }
+
+    // Diff-related methods
+
+    /// Diff two trees, returning the list of changed files
+    ///
+    /// Args:
+    ///     old_tree: The old tree (or commit) ID
+    ///     new_tree: The new tree (or commit) ID
+    ///     options: Optional DiffOptions controlling rename/copy detection and hunk generation
+    ///
+    /// Returns:
+    ///     A list of GitDiffFile entries, each with a status (added/deleted/modified/renamed/copied),
+    ///     old/new paths, old/new blob IDs, and optionally unified hunks
+    #[pyo3(signature = (old_tree, new_tree, options=None))]
+    fn diff_tree(
+        &self,
+        old_tree: &str,
+        new_tree: &str,
+        options: Option<crate::repository::DiffOptions>,
+    ) -> PyResult<Vec<crate::repository::GitDiffFile>> {
+        crate::repository::diff::diff_tree(self, old_tree, new_tree, options)
+    }
+
+    /// Convenience wrapper around `diff_tree` that diffs two commits
+    ///
+    /// Args:
+    ///     old: The old commit ID
+    ///     new: The new commit ID
+    ///     options: Optional DiffOptions controlling rename/copy detection and hunk generation
+    ///
+    /// Returns:
+    ///     A list of GitDiffFile entries describing what changed
+    #[pyo3(signature = (old, new, options=None))]
+    fn diff_commits(
+        &self,
+        old: &str,
+        new: &str,
+        options: Option<crate::repository::DiffOptions>,
+    ) -> PyResult<Vec<crate::repository::GitDiffFile>> {
+        crate::repository::diff::diff_commits(self, old, new, options)
+    }
+
+    /// Diff a commit against its first parent
+    ///
+    /// Args:
+    ///     commit: The commit ID to diff
+    ///
+    /// Returns:
+    ///     A list of GitDiffFile entries describing what changed
+    fn diff_commit_to_parent(&self, commit: &str) -> PyResult<Vec<crate::repository::GitDiffFile>> {
+        crate::repository::diff::diff_commit_to_parent(self, commit)
+    }
+
+    // Reflog-related methods
+
+    /// Read the reflog for a reference, most-recent entry first
+    ///
+    /// Args:
+    ///     ref_name: The reference name (e.g., "HEAD", "refs/heads/main")
+    ///     limit: If given, return at most this many of the most-recent entries
+    ///
+    /// Returns:
+    ///     A list of ReflogEntry objects
+    #[pyo3(signature = (ref_name, limit=None))]
+    fn reflog(&self, ref_name: &str, limit: Option<usize>) -> PyResult<Vec<crate::repository::ReflogEntry>> {
+        crate::repository::reflog::reflog(self, ref_name, limit)
+    }
+
+    /// Check whether a reference has a reflog
+    ///
+    /// Args:
+    ///     ref_name: The reference name (e.g., "HEAD", "refs/heads/main")
+    fn reflog_exists(&self, ref_name: &str) -> PyResult<bool> {
+        crate::repository::reflog::reflog_exists(self, ref_name)
+    }
+
+    // Write-related methods
+
+    /// Write the currently staged index to a tree object
+    ///
+    /// Returns:
+    ///     The new tree's object ID
+    fn write_tree_from_index(&self) -> PyResult<String> {
+        crate::repository::write::write_tree_from_index(self)
+    }
+
+    /// Create a new commit
+    ///
+    /// Args:
+    ///     message: The commit message
+    ///     tree_id: The tree object ID this commit points to
+    ///     parents: The parent commit IDs
+    ///     update_ref: The reference to update to the new commit (e.g., "HEAD"), or None to skip updating a ref
+    ///     author: An (name, email, unix_time) tuple, or None to use the repository's configured signature
+    ///     committer: An (name, email, unix_time) tuple, or None to use the repository's configured signature
+    ///
+    /// Returns:
+    ///     The new commit's object ID
+    #[pyo3(signature = (message, tree_id, parents, update_ref=None, author=None, committer=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn create_commit(
+        &self,
+        message: &str,
+        tree_id: &str,
+        parents: Vec<String>,
+        update_ref: Option<&str>,
+        author: Option<crate::repository::write::SignatureTuple>,
+        committer: Option<crate::repository::write::SignatureTuple>,
+    ) -> PyResult<String> {
+        crate::repository::write::create_commit(self, update_ref, author, committer, message, tree_id, parents)
+    }
+
+    /// Write `data` as a new blob object
+    ///
+    /// Args:
+    ///     data: The blob's raw bytes
+    ///
+    /// Returns:
+    ///     The new blob's object ID
+    fn write_blob(&self, data: &[u8]) -> PyResult<String> {
+        crate::repository::write::write_blob(self, data)
+    }
+
+    /// Create a `TreeBuilder` for incrementally constructing a new tree object
+    ///
+    /// Returns:
+    ///     A TreeBuilder with `insert(name, id, mode)` and `write()` methods
+    fn tree_builder(&self) -> crate::repository::TreeBuilder {
+        crate::repository::write::tree_builder(self)
+    }
+
+    /// Create an annotated tag object and its `refs/tags/<name>` reference
+    ///
+    /// Args:
+    ///     name: The tag's name, without the `refs/tags/` prefix
+    ///     target: The object ID the tag points to
+    ///     message: The tag message
+    ///     tagger: An (name, email, unix_time) tuple, or None to use the repository's configured signature
+    ///     force: If True, overwrite an existing tag reference with the same name
+    ///
+    /// Returns:
+    ///     The new tag object's ID
+    #[pyo3(signature = (name, target, message, tagger=None, force=false))]
+    fn create_tag(
+        &self,
+        name: &str,
+        target: &str,
+        message: &str,
+        tagger: Option<crate::repository::write::SignatureTuple>,
+        force: bool,
+    ) -> PyResult<String> {
+        crate::repository::write::create_tag(self, name, target, tagger, message, force)
+    }
+
+    /// Blame a file at a revision, attributing each line to the commit that last changed it
+    ///
+    /// Walks first-parent-and-merge history, so a line introduced on a branch and then merged in
+    /// unchanged is still attributed to the commit that actually introduced it.
+    ///
+    /// Args:
+    ///     path: The path of the file to blame, relative to the repository root
+    ///     rev: The revision to start the blame from
+    ///     ranges: Optional list of 1-based, inclusive `(start, end)` line ranges to restrict the
+    ///         blame to; defaults to the whole file
+    ///
+    /// Returns:
+    ///     A list of BlameHunk, each covering a contiguous range attributed to one commit
+    ///
+    /// Raises:
+    ///     RepositoryError: If the revision is invalid or a given range is empty
+    #[pyo3(signature = (path, rev="HEAD", ranges=None))]
+    fn blame(&self, path: &str, rev: &str, ranges: Option<Vec<(usize, usize)>>) -> PyResult<Vec<crate::repository::BlameHunk>> {
+        crate::repository::blame::blame(self, path, rev, ranges)
+    }
+
+    /// Like `blame`, but returns an iterator that yields each BlameHunk as it's resolved
+    ///
+    /// Args:
+    ///     path: The path of the file to blame, relative to the repository root
+    ///     rev: The revision to start the blame from
+    ///     ranges: Optional list of 1-based, inclusive `(start, end)` line ranges to restrict the
+    ///         blame to; defaults to the whole file
+    ///
+    /// Returns:
+    ///     A BlameStream yielding BlameHunk instances
+    ///
+    /// Raises:
+    ///     RepositoryError: If the revision is invalid or a given range is empty
+    #[pyo3(signature = (path, rev="HEAD", ranges=None))]
+    fn blame_stream(
+        &self,
+        path: &str,
+        rev: &str,
+        ranges: Option<Vec<(usize, usize)>>,
+    ) -> PyResult<crate::repository::BlameStream> {
+        crate::repository::blame::blame_stream(self, path, rev, ranges)
+    }
+
+    /// Describe a commit in terms of its nearest reachable tag, like `git describe`
+    ///
+    /// Args:
+    ///     committish: The commit to describe
+    ///     tags: If True, also consider lightweight tags, not just annotated ones
+    ///     abbrev: The number of hex digits to use for the abbreviated commit hash
+    ///     dirty_suffix: If given, appended to the result when the worktree has uncommitted changes
+    ///     always: If True, fall back to the abbreviated commit hash when no tag is reachable
+    ///
+    /// Returns:
+    ///     A description like "v1.2.3-5-gabcdef0", or just "v1.2.3" for an exact match
+    ///
+    /// Raises:
+    ///     RepositoryError: If `committish` is invalid, or no tag is reachable and `always` is false
+    #[pyo3(signature = (committish="HEAD", tags=false, abbrev=7, dirty_suffix=None, always=false))]
+    fn describe(
+        &self,
+        committish: &str,
+        tags: bool,
+        abbrev: usize,
+        dirty_suffix: Option<&str>,
+        always: bool,
+    ) -> PyResult<String> {
+        crate::repository::describe::describe(self, committish, tags, abbrev, dirty_suffix, always)
+    }
+
+    /// Snapshot a tree (or a commit, resolved to its tree) into a tar/tar.gz archive
+    ///
+    /// Recursively walks the tree, preserving Unix file modes (regular 0644/0755). Symlinks become
+    /// tar symlink entries whose link name is the target; their entry body is empty.
+    ///
+    /// Args:
+    ///     tree_or_commit_id: The tree or commit to snapshot
+    ///     format: `"tar"` for a plain tarball, or `"tar.gz"` for a gzip-compressed one
+    ///     path: If given, write the archive to this path instead of returning its bytes
+    ///
+    /// Returns:
+    ///     The archive's bytes, or None if `path` was given
+    #[pyo3(signature = (tree_or_commit_id, format="tar.gz", path=None))]
+    fn write_archive(&self, tree_or_commit_id: &str, format: &str, path: Option<&str>) -> PyResult<Option<Vec<u8>>> {
+        let bytes = crate::repository::archive::write_archive(self, tree_or_commit_id, format)?;
+        match path {
+            Some(path) => {
+                std::fs::write(path, &bytes)
+                    .map_err(|err| crate::errors::fs_error(format!("Failed to write archive to '{}': {}", path, err)))?;
+                Ok(None)
+            }
+            None => Ok(Some(bytes)),
+        }
+    }
+
+    /// Render a commit as an RFC 2822 mbox-style patch email, in the spirit of `git format-patch`
+    ///
+    /// Args:
+    ///     commit_id: The commit to render
+    ///
+    /// Returns:
+    ///     The patch text: `From <sha> <date>` separator, headers, message, a `---` line followed
+    ///     by the diffstat, the unified diff against the first parent, and a trailing `--`
+    ///     signature with the version only
+    fn format_patch(&self, commit_id: &str) -> PyResult<String> {
+        crate::repository::patch::format_patch(self, commit_id)
+    }
+
+    /// Drop all cached objects and headers, and reset hit/miss counters
+    ///
+    /// With the cache disabled (`cache_size=0`) there are no entries to drop, but lookups still
+    /// count as misses, so the counters are reset all the same.
+    fn clear_cache(&self) {
+        self.cache.clear();
+    }
+
+    /// Hit/miss counters and current entry count for the object cache
+    fn cache_stats(&self) -> CacheStats {
+        self.cache.stats()
+    }
 }
@@ -0,0 +1,128 @@
+use gix_hash::ObjectId;
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+use crate::errors::{object_error, repository_error};
+use crate::repository::core::Repository;
+
+/// Find the nearest tag reachable from `committish` and format it like `git describe`
+///
+/// Mirrors git2's `Describe`/`DescribeOptions`: walks the first-parent chain from `committish`,
+/// counting commits until a tag is found. Ties (multiple tags on the same commit) are broken by
+/// preferring an annotated tag over a lightweight one, since that matches `git describe`'s
+/// default of ignoring lightweight tags unless `tags=True` is given.
+///
+/// Args:
+///     committish: The commit to describe, defaulting to "HEAD"
+///     tags: If True, also consider lightweight tags, not just annotated ones
+///     abbrev: The number of hex digits to use for the abbreviated commit hash
+///     dirty_suffix: If given, appended to the result when the worktree has uncommitted changes
+///     always: If True, fall back to the abbreviated commit hash when no tag is reachable
+///
+/// Returns:
+///     A description like `v1.2.3-5-gabcdef0`, or just `v1.2.3` for an exact match
+///
+/// Raises:
+///     RepositoryError: If `committish` is invalid, or no tag is reachable and `always` is false
+pub(crate) fn describe(
+    repo: &Repository,
+    committish: &str,
+    tags: bool,
+    abbrev: usize,
+    dirty_suffix: Option<&str>,
+    always: bool,
+) -> PyResult<String> {
+    let start_id = repo
+        .inner
+        .rev_parse_single(committish)
+        .map_err(|err| repository_error(format!("Failed to resolve revision '{}': {}", committish, err)))?
+        .detach();
+
+    let tag_for_commit = collect_tags(repo, tags)?;
+
+    let mut distance = 0u32;
+    let mut current_id = start_id;
+    let mut found: Option<String> = None;
+
+    loop {
+        if let Some((name, _)) = tag_for_commit.get(&current_id) {
+            found = Some(name.clone());
+            break;
+        }
+
+        let commit = repo
+            .inner
+            .find_commit(current_id)
+            .map_err(|err| object_error(format!("Failed to find commit '{}': {}", current_id, err)))?;
+
+        match commit.parent_ids().next() {
+            Some(parent_id) => {
+                current_id = parent_id.detach();
+                distance += 1;
+            }
+            None => break,
+        }
+    }
+
+    let short_id = start_id.to_hex_with_len(abbrev).to_string();
+
+    let mut description = match found {
+        Some(tag) if distance == 0 => tag,
+        Some(tag) => format!("{}-{}-g{}", tag, distance, short_id),
+        None if always => short_id,
+        None => {
+            return Err(repository_error(format!(
+                "No tag reachable from '{}' and `always` is false",
+                committish
+            )))
+        }
+    };
+
+    if let Some(suffix) = dirty_suffix {
+        if repo.inner.is_dirty().unwrap_or(false) {
+            description.push_str(suffix);
+        }
+    }
+
+    Ok(description)
+}
+
+/// Build a map from the commit a tag points at to `(name, is_annotated)`, preferring annotated
+/// tags when more than one tag points at the same commit
+fn collect_tags(repo: &Repository, include_lightweight: bool) -> PyResult<HashMap<ObjectId, (String, bool)>> {
+    let mut tag_for_commit = HashMap::new();
+
+    let platform = repo
+        .inner
+        .references()
+        .map_err(|err| repository_error(format!("Failed to access references: {}", err)))?;
+    let tag_refs = platform
+        .tags()
+        .map_err(|err| repository_error(format!("Failed to list tags: {}", err)))?;
+
+    for tag_ref in tag_refs.filter_map(Result::ok) {
+        let mut tag_ref = tag_ref;
+        let name = tag_ref.name().shorten().to_string();
+
+        let is_annotated = matches!(
+            tag_ref.target(),
+            gix::refs::TargetRef::Object(id)
+                if repo.inner.find_object(id).map(|obj| obj.kind == gix::object::Kind::Tag).unwrap_or(false)
+        );
+
+        if is_annotated || include_lightweight {
+            if let Ok(commit_id) = tag_ref.peel_to_id_in_place() {
+                let commit_id = commit_id.detach();
+                let should_replace = match tag_for_commit.get(&commit_id) {
+                    Some((_, existing_annotated)) => is_annotated && !existing_annotated,
+                    None => true,
+                };
+                if should_replace {
+                    tag_for_commit.insert(commit_id, (name, is_annotated));
+                }
+            }
+        }
+    }
+
+    Ok(tag_for_commit)
+}
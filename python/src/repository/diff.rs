@@ -0,0 +1,479 @@
+use gix_hash::ObjectId;
+use pyo3::prelude::*;
+
+use crate::errors::diff_error;
+use crate::repository::core::Repository;
+
+/// Options controlling how a diff between two trees/commits is computed
+#[pyclass(unsendable)]
+#[derive(Clone)]
+pub struct DiffOptions {
+    #[pyo3(get, set)]
+    pub find_renames: bool,
+    #[pyo3(get, set)]
+    pub find_copies: bool,
+    #[pyo3(get, set)]
+    pub rename_similarity_threshold: f32,
+    #[pyo3(get, set)]
+    pub context_lines: u32,
+    #[pyo3(get, set)]
+    pub ignore_whitespace: bool,
+    #[pyo3(get, set)]
+    pub include_hunks: bool,
+}
+
+#[pymethods]
+impl DiffOptions {
+    #[new]
+    #[pyo3(signature = (
+        find_renames=true,
+        find_copies=false,
+        rename_similarity_threshold=0.5,
+        context_lines=3,
+        ignore_whitespace=false,
+        include_hunks=false
+    ))]
+    fn new(
+        find_renames: bool,
+        find_copies: bool,
+        rename_similarity_threshold: f32,
+        context_lines: u32,
+        ignore_whitespace: bool,
+        include_hunks: bool,
+    ) -> Self {
+        Self {
+            find_renames,
+            find_copies,
+            rename_similarity_threshold,
+            context_lines,
+            ignore_whitespace,
+            include_hunks,
+        }
+    }
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        Self {
+            find_renames: true,
+            find_copies: false,
+            rename_similarity_threshold: 0.5,
+            context_lines: 3,
+            ignore_whitespace: false,
+            include_hunks: false,
+        }
+    }
+}
+
+/// A single line within a diff hunk
+#[pyclass(unsendable)]
+#[derive(Clone)]
+pub struct DiffLine {
+    #[pyo3(get)]
+    pub origin: String,
+    #[pyo3(get)]
+    pub content: String,
+}
+
+/// A contiguous, changed region of a file
+#[pyclass(unsendable)]
+#[derive(Clone)]
+pub struct DiffHunk {
+    #[pyo3(get)]
+    pub old_start: u32,
+    #[pyo3(get)]
+    pub old_lines: u32,
+    #[pyo3(get)]
+    pub new_start: u32,
+    #[pyo3(get)]
+    pub new_lines: u32,
+    #[pyo3(get)]
+    pub lines: Vec<DiffLine>,
+}
+
+/// A single changed file within a commit-to-commit diff
+#[pyclass(unsendable)]
+pub struct GitDiffFile {
+    #[pyo3(get)]
+    pub path: String,
+    #[pyo3(get)]
+    pub old_path: Option<String>,
+    #[pyo3(get)]
+    pub change_kind: String,
+    #[pyo3(get)]
+    pub old_id: Option<String>,
+    #[pyo3(get)]
+    pub new_id: Option<String>,
+    #[pyo3(get)]
+    pub is_binary: bool,
+    #[pyo3(get)]
+    pub hunks: Option<Vec<DiffHunk>>,
+}
+
+fn resolve_tree(repo: &Repository, spec: &str) -> PyResult<gix::Tree<'_>> {
+    let id = ObjectId::from_hex(spec.as_bytes()).map_err(|_| diff_error(format!("Invalid object ID: {}", spec)))?;
+    let object = repo
+        .inner
+        .find_object(id)
+        .map_err(|err| diff_error(format!("Failed to find object {}: {}", spec, err)))?;
+
+    match object.kind {
+        gix::object::Kind::Commit => object
+            .into_commit()
+            .tree()
+            .map_err(|err| diff_error(format!("Failed to get tree for commit {}: {}", spec, err))),
+        gix::object::Kind::Tree => object
+            .try_into_tree()
+            .map_err(|err| diff_error(format!("Object {} is not a tree: {}", spec, err))),
+        other => Err(diff_error(format!("Object {} has unsupported kind {:?}", spec, other))),
+    }
+}
+
+/// Diff two commits, resolving each to its tree and delegating to [`diff_tree`]
+pub(crate) fn diff_commits(
+    repo: &Repository,
+    old: &str,
+    new: &str,
+    options: Option<DiffOptions>,
+) -> PyResult<Vec<GitDiffFile>> {
+    diff_tree(repo, old, new, options)
+}
+
+/// Diff two trees (or anything that resolves to one, including commit IDs), returning the list of
+/// changed files with status (added/deleted/modified/renamed/copied), old/new paths, old/new blob
+/// IDs, and optionally unified hunks
+pub(crate) fn diff_tree(
+    repo: &Repository,
+    old: &str,
+    new: &str,
+    options: Option<DiffOptions>,
+) -> PyResult<Vec<GitDiffFile>> {
+    let options = options.unwrap_or_default();
+    let old_tree = resolve_tree(repo, old)?;
+    let new_tree = resolve_tree(repo, new)?;
+
+    let mut files = Vec::new();
+    let mut changes = old_tree
+        .changes()
+        .map_err(|err| diff_error(format!("Failed to set up tree diff: {}", err)))?;
+
+    if options.find_renames || options.find_copies {
+        changes.track_rewrites(Some(gix::diff::rewrites::Rewrites {
+            copies: options.find_copies.then_some(gix::diff::rewrites::Copies::default()),
+            percentage: Some(options.rename_similarity_threshold),
+            limit: 0,
+        }));
+    }
+
+    changes
+        .for_each_to_obtain_tree(&new_tree, |change| {
+            let (change_kind, old_id, new_id, old_path, path) = match &change {
+                gix::object::tree::diff::Change::Addition { id, location, .. } => {
+                    ("added".to_string(), None, Some(id.to_string()), None, location.to_string())
+                }
+                gix::object::tree::diff::Change::Deletion { id, location, .. } => {
+                    ("deleted".to_string(), Some(id.to_string()), None, None, location.to_string())
+                }
+                gix::object::tree::diff::Change::Modification {
+                    previous_id,
+                    id,
+                    location,
+                    ..
+                } => (
+                    "modified".to_string(),
+                    Some(previous_id.to_string()),
+                    Some(id.to_string()),
+                    None,
+                    location.to_string(),
+                ),
+                gix::object::tree::diff::Change::Rewrite {
+                    source_id,
+                    id,
+                    source_location,
+                    location,
+                    copy,
+                    ..
+                } => (
+                    if *copy { "copied".to_string() } else { "renamed".to_string() },
+                    Some(source_id.to_string()),
+                    Some(id.to_string()),
+                    Some(source_location.to_string()),
+                    location.to_string(),
+                ),
+            };
+
+            let is_binary = [old_id.as_deref(), new_id.as_deref()]
+                .into_iter()
+                .flatten()
+                .any(|id| blob_is_binary(repo, id));
+
+            let hunks = if options.include_hunks && !is_binary {
+                compute_hunks(repo, old_id.as_deref(), new_id.as_deref(), options.ignore_whitespace, options.context_lines)
+            } else {
+                None
+            };
+
+            files.push(GitDiffFile {
+                path,
+                old_path,
+                change_kind,
+                old_id,
+                new_id,
+                is_binary,
+                hunks,
+            });
+
+            Ok::<_, std::convert::Infallible>(gix::object::tree::diff::Action::Continue)
+        })
+        .map_err(|err| diff_error(format!("Failed to compute diff: {}", err)))?;
+
+    Ok(files)
+}
+
+/// Diff a commit against its first parent (or against an empty tree if it has none)
+pub(crate) fn diff_commit_to_parent(repo: &Repository, commit: &str) -> PyResult<Vec<GitDiffFile>> {
+    let id =
+        ObjectId::from_hex(commit.as_bytes()).map_err(|_| diff_error(format!("Invalid object ID: {}", commit)))?;
+    let commit_obj = repo
+        .inner
+        .find_commit(id)
+        .map_err(|err| diff_error(format!("Failed to find commit {}: {}", commit, err)))?;
+
+    match commit_obj.parent_ids().next() {
+        Some(parent_id) => diff_commits(repo, &parent_id.to_string(), commit, None),
+        None => diff_commits(repo, &ObjectId::empty_tree(repo.inner.object_hash()).to_string(), commit, None),
+    }
+}
+
+/// Number of leading bytes sampled to decide whether a blob is binary, matching git's own heuristic.
+const BINARY_SAMPLE_SIZE: usize = 8000;
+
+/// A blob is considered binary if a NUL byte appears anywhere in its first [`BINARY_SAMPLE_SIZE`]
+/// bytes.
+pub(crate) fn looks_binary(data: &[u8]) -> bool {
+    data.iter().take(BINARY_SAMPLE_SIZE).any(|&byte| byte == 0)
+}
+
+pub(crate) fn blob_is_binary(repo: &Repository, id: &str) -> bool {
+    ObjectId::from_hex(id.as_bytes())
+        .ok()
+        .and_then(|id| repo.inner.find_blob(id).ok())
+        .is_some_and(|blob| looks_binary(&blob.data))
+}
+
+/// A single step of the shortest edit script between two line sequences.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum DiffOp {
+    Equal { old: usize, new: usize },
+    Delete { old: usize },
+    Insert { new: usize },
+}
+
+/// Compute the shortest edit script turning `old` into `new` using Myers' O(ND) algorithm.
+///
+/// Tracks the furthest-reaching D-path on each diagonal `k` in a `v` array, recording one frontier
+/// snapshot per `d`, then backtracks through those snapshots to recover the insert/delete/equal
+/// runs that make up the script.
+pub(crate) fn myers_diff(old: &[&str], new: &[&str]) -> Vec<DiffOp> {
+    let n = old.len() as i64;
+    let m = new.len() as i64;
+    let max = (n + m).max(1);
+    let size = (2 * max + 1) as usize;
+
+    let mut v = vec![0i64; size];
+    let mut trace: Vec<Vec<i64>> = Vec::new();
+
+    'search: for d in 0..=max {
+        trace.push(v.clone());
+        for k in (-d..=d).step_by(2) {
+            let idx = (k + max) as usize;
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                v[idx + 1]
+            } else {
+                v[idx - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut ops = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for d in (0..trace.len() as i64).rev() {
+        let v = &trace[d as usize];
+        let k = x - y;
+        let idx = (k + max) as usize;
+        let prev_k = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = v[(prev_k + max) as usize];
+        let prev_y = prev_x - prev_k;
+
+        while x > prev_x && y > prev_y {
+            ops.push(DiffOp::Equal {
+                old: (x - 1) as usize,
+                new: (y - 1) as usize,
+            });
+            x -= 1;
+            y -= 1;
+        }
+        if d > 0 {
+            if x == prev_x {
+                ops.push(DiffOp::Insert { new: (y - 1) as usize });
+            } else {
+                ops.push(DiffOp::Delete { old: (x - 1) as usize });
+            }
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    ops.reverse();
+    ops
+}
+
+/// Maximal runs of non-equal ops, as `(start, end)` index ranges into `ops` (end exclusive).
+fn change_groups(ops: &[DiffOp]) -> Vec<(usize, usize)> {
+    let mut groups = Vec::new();
+    let mut i = 0;
+    while i < ops.len() {
+        if matches!(ops[i], DiffOp::Equal { .. }) {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < ops.len() && !matches!(ops[i], DiffOp::Equal { .. }) {
+            i += 1;
+        }
+        groups.push((start, i));
+    }
+    groups
+}
+
+/// Expand each change group by `context` equal lines on either side, coalescing groups whose
+/// expanded windows overlap or touch, i.e. groups separated by at most `2 * context` equal lines.
+fn hunk_windows(ops: &[DiffOp], context: usize) -> Vec<(usize, usize)> {
+    let mut windows: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in change_groups(ops) {
+        let window_start = start.saturating_sub(context);
+        let window_end = (end + context).min(ops.len());
+        match windows.last_mut() {
+            Some(last) if window_start <= last.1 => last.1 = window_end,
+            _ => windows.push((window_start, window_end)),
+        }
+    }
+    windows
+}
+
+/// Render the shortest edit script between `old` and `new` as unified-diff hunks with `context`
+/// lines of surrounding, unchanged context, coalescing adjacent changes per [`hunk_windows`].
+pub(crate) fn unified_hunks(old: &[&str], new: &[&str], context: u32) -> Vec<DiffHunk> {
+    unified_hunks_with_keys(old, old, new, new, context)
+}
+
+/// Like [`unified_hunks`], but lines are compared using `old_keys`/`new_keys` while the hunk
+/// content is taken from `old`/`new` — lets callers diff on a normalized key (e.g. with
+/// whitespace collapsed) while still displaying the original line content.
+pub(crate) fn unified_hunks_with_keys(
+    old: &[&str],
+    old_keys: &[&str],
+    new: &[&str],
+    new_keys: &[&str],
+    context: u32,
+) -> Vec<DiffHunk> {
+    let ops = myers_diff(old_keys, new_keys);
+    let context = context as usize;
+
+    // Cumulative old/new line index consumed *before* each op, used to derive each hunk's
+    // `@@ -old_start,old_lines +new_start,new_lines @@` header from its op-index window.
+    let mut old_before = Vec::with_capacity(ops.len());
+    let mut new_before = Vec::with_capacity(ops.len());
+    let (mut o, mut n) = (0usize, 0usize);
+    for op in &ops {
+        old_before.push(o);
+        new_before.push(n);
+        match op {
+            DiffOp::Equal { .. } => {
+                o += 1;
+                n += 1;
+            }
+            DiffOp::Delete { .. } => o += 1,
+            DiffOp::Insert { .. } => n += 1,
+        }
+    }
+
+    hunk_windows(&ops, context)
+        .into_iter()
+        .map(|(start, end)| {
+            let window = &ops[start..end];
+            let old_lines_in_hunk = window.iter().filter(|op| !matches!(op, DiffOp::Insert { .. })).count();
+            let new_lines_in_hunk = window.iter().filter(|op| !matches!(op, DiffOp::Delete { .. })).count();
+
+            let lines = window
+                .iter()
+                .map(|op| match *op {
+                    DiffOp::Equal { old: idx, .. } => DiffLine {
+                        origin: " ".to_string(),
+                        content: old[idx].to_string(),
+                    },
+                    DiffOp::Delete { old: idx } => DiffLine {
+                        origin: "-".to_string(),
+                        content: old[idx].to_string(),
+                    },
+                    DiffOp::Insert { new: idx } => DiffLine {
+                        origin: "+".to_string(),
+                        content: new[idx].to_string(),
+                    },
+                })
+                .collect();
+
+            DiffHunk {
+                old_start: if old_lines_in_hunk == 0 { 0 } else { old_before[start] as u32 + 1 },
+                old_lines: old_lines_in_hunk as u32,
+                new_start: if new_lines_in_hunk == 0 { 0 } else { new_before[start] as u32 + 1 },
+                new_lines: new_lines_in_hunk as u32,
+                lines,
+            }
+        })
+        .collect()
+}
+
+/// Materialize textual hunks between two blobs by running a Myers diff over their line content.
+fn compute_hunks(
+    repo: &Repository,
+    old_id: Option<&str>,
+    new_id: Option<&str>,
+    ignore_whitespace: bool,
+    context_lines: u32,
+) -> Option<Vec<DiffHunk>> {
+    let old_data = old_id.and_then(|id| ObjectId::from_hex(id.as_bytes()).ok()).and_then(|id| repo.inner.find_blob(id).ok());
+    let new_data = new_id.and_then(|id| ObjectId::from_hex(id.as_bytes()).ok()).and_then(|id| repo.inner.find_blob(id).ok());
+
+    let old_text = old_data.as_ref().map(|blob| String::from_utf8_lossy(&blob.data).into_owned());
+    let new_text = new_data.as_ref().map(|blob| String::from_utf8_lossy(&blob.data).into_owned());
+
+    let normalize = |line: &str| -> String {
+        if ignore_whitespace {
+            line.split_whitespace().collect::<Vec<_>>().join(" ")
+        } else {
+            line.to_string()
+        }
+    };
+
+    let old_lines: Vec<&str> = old_text.as_deref().map(|s| s.lines().collect()).unwrap_or_default();
+    let new_lines: Vec<&str> = new_text.as_deref().map(|s| s.lines().collect()).unwrap_or_default();
+    let old_keys: Vec<String> = old_lines.iter().map(|line| normalize(line)).collect();
+    let new_keys: Vec<String> = new_lines.iter().map(|line| normalize(line)).collect();
+    let old_key_refs: Vec<&str> = old_keys.iter().map(String::as_str).collect();
+    let new_key_refs: Vec<&str> = new_keys.iter().map(String::as_str).collect();
+
+    Some(unified_hunks_with_keys(&old_lines, &old_key_refs, &new_lines, &new_key_refs, context_lines))
+}
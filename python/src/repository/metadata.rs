@@ -0,0 +1,166 @@
+use gix_hash::ObjectId;
+use pyo3::prelude::*;
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+use crate::errors::{object_error, repository_error};
+use crate::repository::core::Repository;
+
+/// A signature with its timestamp rendered using the requested `time_format`
+#[pyclass(unsendable)]
+#[derive(Clone)]
+pub struct SignatureInfo {
+    #[pyo3(get)]
+    pub name: String,
+    #[pyo3(get)]
+    pub email: String,
+    #[pyo3(get)]
+    pub time: String,
+}
+
+/// A fully decoded commit: signatures, message, tree, and parent IDs
+///
+/// Unlike the raw `GitObject` returned by `find_commit`, the fields here are already parsed, and
+/// `author.time`/`committer.time` are rendered via `time_format` instead of left as raw bytes.
+#[pyclass(unsendable)]
+pub struct CommitInfo {
+    #[pyo3(get)]
+    pub id: String,
+    #[pyo3(get)]
+    pub tree: String,
+    #[pyo3(get)]
+    pub parents: Vec<String>,
+    #[pyo3(get)]
+    pub author: SignatureInfo,
+    #[pyo3(get)]
+    pub committer: SignatureInfo,
+    #[pyo3(get)]
+    pub message: String,
+    #[pyo3(get)]
+    pub summary: String,
+}
+
+/// A fully decoded annotated tag: tagger signature, message, and the tagged object
+#[pyclass(unsendable)]
+pub struct TagInfo {
+    #[pyo3(get)]
+    pub id: String,
+    #[pyo3(get)]
+    pub target: String,
+    #[pyo3(get)]
+    pub target_kind: String,
+    #[pyo3(get)]
+    pub name: String,
+    #[pyo3(get)]
+    pub tagger: Option<SignatureInfo>,
+    #[pyo3(get)]
+    pub message: String,
+}
+
+fn interned_format_strings() -> &'static Mutex<HashSet<&'static str>> {
+    static INTERNED: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+    INTERNED.get_or_init(|| Mutex::new(HashSet::new()))
+}
+
+/// Intern `value` into a `&'static str`, leaking at most once per distinct string
+///
+/// `CustomFormat` requires a `'static` string, but callers naturally pass the same handful of
+/// custom format strings repeatedly (e.g. once per commit in a log-rendering loop), so leaking on
+/// every call would grow unbounded; caching the leaked pointer keeps the leak bounded by the
+/// number of distinct format strings ever seen.
+fn intern_format_string(value: &str) -> &'static str {
+    let mut interned = interned_format_strings().lock().unwrap();
+    if let Some(existing) = interned.get(value) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(value.to_string().into_boxed_str());
+    interned.insert(leaked);
+    leaked
+}
+
+/// Parse a `time_format` selector ("unix", "raw", or a custom strftime-style format string) into a
+/// [`gix_date::time::Format`]
+///
+/// A custom string expands only `%Y %m %d %H %M %S %z` and `%%`; other directives are kept verbatim.
+pub(crate) fn parse_time_format(time_format: &str) -> gix_date::time::Format {
+    match time_format {
+        "unix" => gix_date::time::Format::Unix,
+        "raw" => gix_date::time::Format::Raw,
+        custom => gix_date::time::Format::Custom(gix_date::time::CustomFormat::new(intern_format_string(custom))),
+    }
+}
+
+pub(crate) fn render_signature(sig: gix_actor::SignatureRef<'_>, format: gix_date::time::Format) -> SignatureInfo {
+    SignatureInfo {
+        name: sig.name.to_string(),
+        email: sig.email.to_string(),
+        time: sig.time.format(format),
+    }
+}
+
+/// Decode a commit and render its signature timestamps using `time_format`
+///
+/// Args:
+///     id: The commit's object ID
+///     time_format: "unix", "raw", or a custom strftime-style format string (see [`parse_time_format`])
+///
+/// Returns:
+///     A CommitInfo with decoded author/committer/message/tree/parents
+pub(crate) fn commit_info(repo: &Repository, id: &str, time_format: &str) -> PyResult<CommitInfo> {
+    let object_id =
+        ObjectId::from_hex(id.as_bytes()).map_err(|_| repository_error(format!("Invalid object ID: {}", id)))?;
+
+    let commit = repo
+        .inner
+        .find_commit(object_id)
+        .map_err(|err| object_error(format!("Failed to find commit {}: {}", id, err)))?;
+
+    let decoded = commit
+        .decode()
+        .map_err(|err| object_error(format!("Failed to decode commit {}: {}", id, err)))?;
+
+    let format = parse_time_format(time_format);
+
+    Ok(CommitInfo {
+        id: commit.id.to_string(),
+        tree: decoded.tree().to_string(),
+        parents: decoded.parents().map(|p| p.to_string()).collect(),
+        author: render_signature(decoded.author, format),
+        committer: render_signature(decoded.committer, format),
+        message: decoded.message.to_string(),
+        summary: decoded.message().title.trim().to_string(),
+    })
+}
+
+/// Decode an annotated tag and render its tagger timestamp using `time_format`
+///
+/// Args:
+///     id: The tag's object ID
+///     time_format: "unix", "raw", or a custom strftime-style format string (see [`parse_time_format`])
+///
+/// Returns:
+///     A TagInfo with decoded name/target/tagger/message
+pub(crate) fn tag_info(repo: &Repository, id: &str, time_format: &str) -> PyResult<TagInfo> {
+    let object_id =
+        ObjectId::from_hex(id.as_bytes()).map_err(|_| repository_error(format!("Invalid object ID: {}", id)))?;
+
+    let tag = repo
+        .inner
+        .find_tag(object_id)
+        .map_err(|err| object_error(format!("Failed to find tag {}: {}", id, err)))?;
+
+    let decoded = tag
+        .decode()
+        .map_err(|err| object_error(format!("Failed to decode tag {}: {}", id, err)))?;
+
+    let format = parse_time_format(time_format);
+
+    Ok(TagInfo {
+        id: tag.id.to_string(),
+        target: decoded.target.to_string(),
+        target_kind: format!("{:?}", decoded.target_kind),
+        name: decoded.name.to_string(),
+        tagger: decoded.tagger.map(|sig| render_signature(sig, format)),
+        message: decoded.message.to_string(),
+    })
+}
@@ -1,12 +1,36 @@
 // Submodules
+mod archive;
+mod blame;
+pub(crate) mod cache;
 mod config;
 mod core;
+mod describe;
+pub(crate) mod diff;
+mod lfs;
+pub(crate) mod metadata;
 mod objects;
+mod patch;
 mod references;
+mod reflog;
+mod remote;
+mod revision_graph;
 mod revisions;
+mod write;
 
 // Re-export the Repository struct for the public API
-pub use config::Config;
+pub use blame::{BlameHunk, BlameStream};
+pub use cache::CacheStats;
+pub use config::{Config, ConfigEntry};
 pub use core::Repository;
+pub use diff::{DiffHunk, DiffLine, DiffOptions, GitDiffFile};
+pub use lfs::parse_lfs_pointer_py;
+pub use metadata::{CommitInfo, SignatureInfo, TagInfo};
+pub use objects::BlobReader;
+pub use reflog::ReflogEntry;
+pub use references::RefEditSpec;
+pub use remote::{FetchOutcome, GitRemote};
+pub use revision_graph::RevisionGraph;
+pub use revisions::{CommitWalk, RevSpecRange};
+pub use write::TreeBuilder;
 
 // Re-export any other public items
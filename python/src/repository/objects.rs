@@ -1,15 +1,37 @@
+use std::fs;
+use std::io::{Cursor, Read};
+use std::sync::Arc;
+
 use gix_hash::ObjectId;
+use memmap2::Mmap;
 use pyo3::prelude::*;
-use pyo3::types::PyBytes;
+use pyo3::types::{PyByteArray, PyBytes};
 
 use crate::errors::repository_error;
+use crate::repository::cache::CachedObject;
 use crate::repository::core::{GitObject, ObjectHeader, Repository};
 
+const BLOB_READER_CHUNK_SIZE: usize = 64 * 1024;
+
 /// Find a Git object by its ID
+///
+/// Served from the repository's object cache when enabled (see `Repository.open`'s
+/// `cache_size`/`cache_ttl` arguments), so repeated lookups of the same ID skip the odb read.
 pub(crate) fn find_object(repo: &Repository, id: &str) -> PyResult<GitObject> {
     let object_id =
         ObjectId::from_hex(id.as_bytes()).map_err(|_| repository_error(format!("Invalid object ID: {}", id)))?;
 
+    if let Some(cached) = repo.cache.get_object(&object_id) {
+        return Python::with_gil(|py| {
+            let bytes = PyBytes::new(py, &cached.data);
+            Ok(GitObject {
+                id: object_id.to_string(),
+                kind: cached.kind,
+                data: bytes.into(),
+            })
+        });
+    }
+
     repo.inner
         .find_object(object_id)
         .map_err(|err| {
@@ -17,11 +39,20 @@ pub(crate) fn find_object(repo: &Repository, id: &str) -> PyResult<GitObject> {
             repository_error(msg)
         })
         .and_then(|obj| {
+            let kind = format!("{:?}", obj.kind);
+            repo.cache.insert_object(
+                object_id,
+                CachedObject {
+                    kind: kind.clone(),
+                    data: obj.data.clone(),
+                },
+            );
+
             Python::with_gil(|py| {
                 let bytes = PyBytes::new(py, &obj.data);
                 Ok(GitObject {
                     id: obj.id.to_string(),
-                    kind: format!("{:?}", obj.kind),
+                    kind,
                     data: bytes.into(),
                 })
             })
@@ -29,10 +60,29 @@ pub(crate) fn find_object(repo: &Repository, id: &str) -> PyResult<GitObject> {
 }
 
 /// Find a blob object by its ID
-pub(crate) fn find_blob(repo: &Repository, id: &str) -> PyResult<GitObject> {
+///
+/// When `smudge_lfs` is true and the blob is a Git LFS pointer, the real content is read from
+/// the local LFS object store instead, falling back to the raw pointer if it isn't present.
+///
+/// Only the raw (non-smudged) form is served from and written to the object cache, since the
+/// smudged content isn't a pure function of `id` alone.
+pub(crate) fn find_blob(repo: &Repository, id: &str, smudge_lfs: bool) -> PyResult<GitObject> {
     let object_id =
         ObjectId::from_hex(id.as_bytes()).map_err(|_| repository_error(format!("Invalid object ID: {}", id)))?;
 
+    if !smudge_lfs {
+        if let Some(cached) = repo.cache.get_object(&object_id).filter(|cached| cached.kind == "Blob") {
+            return Python::with_gil(|py| {
+                let bytes = PyBytes::new(py, &cached.data);
+                Ok(GitObject {
+                    id: object_id.to_string(),
+                    kind: cached.kind,
+                    data: bytes.into(),
+                })
+            });
+        }
+    }
+
     repo.inner
         .find_blob(object_id)
         .map_err(|err| {
@@ -40,8 +90,24 @@ pub(crate) fn find_blob(repo: &Repository, id: &str) -> PyResult<GitObject> {
             repository_error(msg)
         })
         .and_then(|blob| {
+            if !smudge_lfs {
+                repo.cache.insert_object(
+                    object_id,
+                    CachedObject {
+                        kind: "Blob".to_string(),
+                        data: blob.data.clone(),
+                    },
+                );
+            }
+
+            let data = if smudge_lfs {
+                crate::repository::lfs::smudge(repo, &blob.data)?
+            } else {
+                blob.data.clone()
+            };
+
             Python::with_gil(|py| {
-                let bytes = PyBytes::new(py, &blob.data);
+                let bytes = PyBytes::new(py, &data);
                 Ok(GitObject {
                     id: blob.id.to_string(),
                     kind: "Blob".to_string(),
@@ -121,10 +187,19 @@ pub(crate) fn find_tag(repo: &Repository, id: &str) -> PyResult<GitObject> {
 }
 
 /// Get information about an object without fully decoding it
+///
+/// Served from the repository's header cache when enabled; see [`find_object`]'s cache note.
 pub(crate) fn find_header(repo: &Repository, id: &str) -> PyResult<ObjectHeader> {
     let object_id =
         ObjectId::from_hex(id.as_bytes()).map_err(|_| repository_error(format!("Invalid object ID: {}", id)))?;
 
+    if let Some(cached) = repo.cache.get_header(&object_id) {
+        return Ok(ObjectHeader {
+            kind: cached.kind,
+            size: cached.size,
+        });
+    }
+
     repo.inner
         .find_header(object_id)
         .map_err(|err| {
@@ -132,20 +207,198 @@ pub(crate) fn find_header(repo: &Repository, id: &str) -> PyResult<ObjectHeader>
             repository_error(msg)
         })
         .map(|header| {
-            let kind = header.kind();
+            let kind = format!("{:?}", header.kind());
             let size = header.size();
 
-            ObjectHeader {
-                kind: format!("{:?}", kind),
-                size,
-            }
+            repo.cache.insert_header(
+                object_id,
+                crate::repository::cache::CachedHeader {
+                    kind: kind.clone(),
+                    size,
+                },
+            );
+
+            ObjectHeader { kind, size }
         })
 }
 
 /// Check if an object exists in the repository
+///
+/// A cached header entry is treated as proof of existence without touching the odb again.
 pub(crate) fn has_object(repo: &Repository, id: &str) -> PyResult<bool> {
     let object_id =
         ObjectId::from_hex(id.as_bytes()).map_err(|_| repository_error(format!("Invalid object ID: {}", id)))?;
 
+    if repo.cache.get_header(&object_id).is_some() {
+        return Ok(true);
+    }
+
     Ok(repo.inner.has_object(&object_id))
 }
+
+/// A byte source a [`BlobReader`] streams from: either a lazily-inflating view over a memory-mapped
+/// loose object file, or a fully-decoded buffer for objects gix can't stream directly (packed ones)
+enum BlobBody {
+    Loose(flate2::read::ZlibDecoder<Cursor<MmapBytes>>),
+    Owned(Cursor<Vec<u8>>),
+}
+
+impl Read for BlobBody {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            BlobBody::Loose(inner) => inner.read(buf),
+            BlobBody::Owned(inner) => inner.read(buf),
+        }
+    }
+}
+
+/// Wraps an `Arc<Mmap>` so it can back a `Cursor`, keeping the mapping alive for as long as any
+/// reader built on top of it is
+struct MmapBytes(Arc<Mmap>);
+
+impl AsRef<[u8]> for MmapBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// A forward-only, file-like reader over a blob's content
+///
+/// Backed by a memory-mapped view of the loose object file when the blob is stored loose, inflated
+/// through zlib as it is read, so the whole blob is never held in memory; decoded bytes are not
+/// kept, and data already read cannot be read again. Falls back to a one-time fully-decoded buffer
+/// for packed objects, since gix doesn't expose a streaming reader into pack data.
+#[pyclass(unsendable)]
+pub struct BlobReader {
+    body: BlobBody,
+}
+
+#[pymethods]
+impl BlobReader {
+    /// Read up to `size` bytes, or everything remaining if `size` is negative (the default)
+    #[pyo3(signature = (size=-1))]
+    fn read(&mut self, py: Python<'_>, size: i64) -> PyResult<Py<PyBytes>> {
+        if size < 0 {
+            let mut buf = Vec::new();
+            self.body
+                .read_to_end(&mut buf)
+                .map_err(|err| repository_error(format!("Failed to read blob: {}", err)))?;
+            return Ok(PyBytes::new(py, &buf).into());
+        }
+
+        let mut buf = vec![0u8; size as usize];
+        let read = self
+            .body
+            .read(&mut buf)
+            .map_err(|err| repository_error(format!("Failed to read blob: {}", err)))?;
+        buf.truncate(read);
+        Ok(PyBytes::new(py, &buf).into())
+    }
+
+    /// Read into a caller-provided buffer, returning the number of bytes actually read (0 at EOF)
+    fn readinto(&mut self, buf: &Bound<'_, PyByteArray>) -> PyResult<usize> {
+        // Safety: the GIL is held for the duration of the call, and no Python code runs while
+        // `slice` is borrowed.
+        let slice = unsafe { buf.as_bytes_mut() };
+        self.body
+            .read(slice)
+            .map_err(|err| repository_error(format!("Failed to read blob: {}", err)))
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Iterate in fixed 64 KiB chunks, stopping iteration at EOF
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyBytes>>> {
+        // A single `read()` may return fewer bytes than asked for mid-stream, so keep reading
+        // until the chunk is full or EOF is reached; only the last chunk can be shorter.
+        let mut buf = Vec::with_capacity(BLOB_READER_CHUNK_SIZE);
+        (&mut self.body)
+            .take(BLOB_READER_CHUNK_SIZE as u64)
+            .read_to_end(&mut buf)
+            .map_err(|err| repository_error(format!("Failed to read blob: {}", err)))?;
+        if buf.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(PyBytes::new(py, &buf).into()))
+    }
+}
+
+fn loose_object_path(repo: &gix::Repository, id: ObjectId) -> std::path::PathBuf {
+    let hex = id.to_hex().to_string();
+    repo.git_dir().join("objects").join(&hex[0..2]).join(&hex[2..])
+}
+
+/// Open a streaming, zero-copy-where-possible reader over a blob's content
+///
+/// Args:
+///     id: The blob's object ID
+///
+/// Returns:
+///     A BlobReader supporting `read(n)`, `readinto(buf)`, and chunked iteration
+pub(crate) fn open_blob(repo: &Repository, id: &str) -> PyResult<BlobReader> {
+    let object_id =
+        ObjectId::from_hex(id.as_bytes()).map_err(|_| repository_error(format!("Invalid object ID: {}", id)))?;
+
+    let header = repo
+        .inner
+        .find_header(object_id)
+        .map_err(|err| repository_error(format!("Failed to find object {}: {}", id, err)))?;
+    if header.kind() != gix::object::Kind::Blob {
+        return Err(repository_error(format!("{} is not a blob", id)));
+    }
+
+    let loose_path = loose_object_path(&repo.inner, object_id);
+    if let Ok(file) = fs::File::open(&loose_path) {
+        let mmap = unsafe { Mmap::map(&file) }
+            .map_err(|err| repository_error(format!("Failed to map '{}': {}", loose_path.display(), err)))?;
+        let mut decoder = flate2::read::ZlibDecoder::new(Cursor::new(MmapBytes(Arc::new(mmap))));
+
+        // Loose objects decompress to `"<kind> <size>\0<content>"`; skip past the header before
+        // handing the stream to the caller.
+        let mut byte = [0u8; 1];
+        loop {
+            decoder
+                .read_exact(&mut byte)
+                .map_err(|err| repository_error(format!("Failed to read loose object header: {}", err)))?;
+            if byte[0] == 0 {
+                break;
+            }
+        }
+
+        return Ok(BlobReader {
+            body: BlobBody::Loose(decoder),
+        });
+    }
+
+    // Not stored loose (e.g. packed): fall back to a full decode.
+    let blob = repo
+        .inner
+        .find_blob(object_id)
+        .map_err(|err| repository_error(format!("Failed to find blob {}: {}", id, err)))?;
+    Ok(BlobReader {
+        body: BlobBody::Owned(Cursor::new(blob.data.clone())),
+    })
+}
+
+/// Get a blob's size without decoding its content
+///
+/// Args:
+///     id: The blob's object ID
+///
+/// Returns:
+///     The size of the blob's content in bytes
+pub(crate) fn blob_size(repo: &Repository, id: &str) -> PyResult<u64> {
+    let object_id =
+        ObjectId::from_hex(id.as_bytes()).map_err(|_| repository_error(format!("Invalid object ID: {}", id)))?;
+
+    if let Some(cached) = repo.cache.get_header(&object_id) {
+        return Ok(cached.size);
+    }
+
+    repo.inner
+        .find_header(object_id)
+        .map_err(|err| repository_error(format!("Failed to find object {}: {}", id, err)))
+        .map(|header| header.size())
+}
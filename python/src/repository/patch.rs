@@ -0,0 +1,173 @@
+use gix_hash::ObjectId;
+use pyo3::prelude::*;
+
+use crate::errors::{object_error, repository_error};
+use crate::repository::core::Repository;
+use crate::repository::diff::{diff_tree, DiffOptions};
+use crate::repository::metadata::{parse_time_format, render_signature};
+
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Split `days` since the Unix epoch into a `(year, month, day)` tuple, using Howard Hinnant's
+/// `civil_from_days` algorithm since we otherwise have no calendar dependency to lean on.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let day_of_era = (z - era * 146_097) as u64;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let year = year_of_era as i64 + era * 400;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = if month <= 2 { year + 1 } else { year };
+    (year, month, day)
+}
+
+/// Render a signature timestamp as an RFC 2822 `Date:` header, e.g. `Mon, 1 Jan 2024 12:00:00 +0000`
+fn rfc2822_date(seconds: i64, offset: i32) -> String {
+    let local_seconds = seconds + offset as i64;
+    let days = local_seconds.div_euclid(86_400);
+    let seconds_of_day = local_seconds.rem_euclid(86_400);
+    let (year, month, day) = civil_from_days(days);
+    let hour = seconds_of_day / 3600;
+    let minute = (seconds_of_day % 3600) / 60;
+    let second = seconds_of_day % 60;
+    // 1970-01-01 was a Thursday, index 4 in `WEEKDAYS`.
+    let weekday = WEEKDAYS[((days % 7 + 7 + 4) % 7) as usize];
+    let month_name = MONTHS[(month - 1) as usize];
+
+    let sign = if offset < 0 { '-' } else { '+' };
+    let abs_offset = offset.unsigned_abs();
+    format!(
+        "{}, {} {} {:04} {:02}:{:02}:{:02} {}{:02}{:02}",
+        weekday,
+        day,
+        month_name,
+        year,
+        hour,
+        minute,
+        second,
+        sign,
+        abs_offset / 3600,
+        (abs_offset % 3600) / 60
+    )
+}
+
+/// A `" N files changed, X insertions(+), Y deletions(-)"`-style summary, plus one line per file
+fn diffstat(files: &[crate::repository::GitDiffFile]) -> String {
+    let mut out = String::new();
+    let mut total_insertions = 0usize;
+    let mut total_deletions = 0usize;
+
+    for file in files {
+        if file.is_binary {
+            out.push_str(&format!(" {} | Bin\n", file.path));
+            continue;
+        }
+
+        let mut insertions = 0usize;
+        let mut deletions = 0usize;
+        for hunk in file.hunks.iter().flatten() {
+            for line in &hunk.lines {
+                match line.origin.as_str() {
+                    "+" => insertions += 1,
+                    "-" => deletions += 1,
+                    _ => {}
+                }
+            }
+        }
+        total_insertions += insertions;
+        total_deletions += deletions;
+        out.push_str(&format!(" {} | {} {}\n", file.path, insertions + deletions, "+".repeat(insertions) + &"-".repeat(deletions)));
+    }
+
+    out.push_str(&format!(
+        " {} file{} changed, {} insertion{}(+), {} deletion{}(-)\n",
+        files.len(),
+        if files.len() == 1 { "" } else { "s" },
+        total_insertions,
+        if total_insertions == 1 { "" } else { "s" },
+        total_deletions,
+        if total_deletions == 1 { "" } else { "s" },
+    ));
+    out
+}
+
+/// Render `commit_id` as an RFC 2822 mbox-style patch email, in the spirit of `git format-patch`
+///
+/// Produces a `From <sha> <date>` separator, `From:`/`Date:`/`Subject: [PATCH] <summary>` headers,
+/// the commit's message body, a `---` line followed by the diffstat, the unified diff against its
+/// first parent (or against an empty tree if it has none), and a trailing `--` signature holding
+/// only the gitoxide-python version.
+pub(crate) fn format_patch(repo: &Repository, commit_id: &str) -> PyResult<String> {
+    let id = ObjectId::from_hex(commit_id.as_bytes())
+        .map_err(|_| repository_error(format!("Invalid object ID: {}", commit_id)))?;
+    let commit = repo
+        .inner
+        .find_commit(id)
+        .map_err(|err| object_error(format!("Failed to find commit {}: {}", commit_id, err)))?;
+    let decoded = commit
+        .decode()
+        .map_err(|err| object_error(format!("Failed to decode commit {}: {}", commit_id, err)))?;
+
+    let format = parse_time_format("raw");
+    let author = render_signature(decoded.author, format);
+    let date = rfc2822_date(decoded.author.time.seconds, decoded.author.time.offset);
+
+    let message = decoded.message();
+    let subject = message.title.trim().to_string();
+    let body = message.body.map(|body| body.trim().to_string());
+
+    let parent_spec = match commit.parent_ids().next() {
+        Some(parent_id) => parent_id.to_string(),
+        None => ObjectId::empty_tree(repo.inner.object_hash()).to_string(),
+    };
+    let options = DiffOptions {
+        include_hunks: true,
+        ..DiffOptions::default()
+    };
+    let files = diff_tree(repo, &parent_spec, commit_id, Some(options))?;
+
+    let mut patch = format!(
+        "From {} {}\nFrom: {} <{}>\nDate: {}\nSubject: [PATCH] {}\n\n",
+        commit.id,
+        date,
+        author.name,
+        author.email,
+        date,
+        subject
+    );
+    if let Some(body) = body.filter(|body| !body.is_empty()) {
+        patch.push_str(&body);
+        patch.push('\n');
+    }
+    patch.push_str("---\n");
+    patch.push_str(&diffstat(&files));
+    patch.push('\n');
+
+    for file in &files {
+        patch.push_str(&format!("diff --git a/{} b/{}\n", file.old_path.as_deref().unwrap_or(&file.path), file.path));
+        if file.is_binary {
+            patch.push_str("Binary files differ\n");
+            continue;
+        }
+        for hunk in file.hunks.iter().flatten() {
+            patch.push_str(&format!(
+                "@@ -{},{} +{},{} @@\n",
+                hunk.old_start, hunk.old_lines, hunk.new_start, hunk.new_lines
+            ));
+            for line in &hunk.lines {
+                patch.push_str(&line.origin);
+                patch.push_str(&line.content);
+                patch.push('\n');
+            }
+        }
+    }
+
+    patch.push_str(&format!("--\ngitoxide-python {}\n", env!("CARGO_PKG_VERSION")));
+    Ok(patch)
+}
@@ -36,6 +36,7 @@ pub(crate) fn references(repo: &Repository) -> PyResult<Vec<GitReference>> {
                     name: r.inner.name.as_bstr().to_string(),
                     target,
                     is_symbolic,
+                    peeled_target: None,
                 });
             }
             Err(err) => {
@@ -100,10 +101,59 @@ pub(crate) fn find_reference(repo: &Repository, name: &str) -> PyResult<GitRefer
                 name: r.inner.name.as_bstr().to_string(),
                 target,
                 is_symbolic,
+                peeled_target: None,
             }
         })
 }
 
+/// Find a reference by name, optionally peeling it all the way down to its final object id
+pub(crate) fn find_reference_peel(repo: &Repository, name: &str, peel: bool) -> PyResult<GitReference> {
+    let mut reference = find_reference(repo, name)?;
+
+    if peel {
+        let mut gix_reference = repo
+            .inner
+            .find_reference(name)
+            .map_err(|err| repository_error(format!("Failed to find reference '{}': {}", name, err)))?;
+        let peeled = gix_reference
+            .peel_to_id_in_place()
+            .map_err(|err| repository_error(format!("Failed to peel reference '{}': {}", name, err)))?;
+        reference.peeled_target = Some(peeled.to_string());
+    }
+
+    Ok(reference)
+}
+
+/// Iterate references whose full name starts with `prefix` (e.g. `"refs/tags/"`)
+pub(crate) fn references_prefixed(repo: &Repository, prefix: &str) -> PyResult<Vec<GitReference>> {
+    let platform = repo
+        .inner
+        .references()
+        .map_err(|err| repository_error(format!("Failed to get references: {}", err)))?;
+
+    let refs_iter = platform
+        .prefixed(prefix)
+        .map_err(|err| repository_error(format!("Failed to iterate references prefixed with '{}': {}", prefix, err)))?;
+
+    let mut refs = Vec::new();
+    for result in refs_iter {
+        let r = result.map_err(|err| repository_error(format!("Error with reference: {}", err)))?;
+        let (target, is_symbolic) = match r.inner.target {
+            gix_ref::Target::Symbolic(name) => (name.as_bstr().to_string(), true),
+            gix_ref::Target::Object(id) => (id.to_string(), false),
+        };
+
+        refs.push(GitReference {
+            name: r.inner.name.as_bstr().to_string(),
+            target,
+            is_symbolic,
+            peeled_target: None,
+        });
+    }
+
+    Ok(refs)
+}
+
 /// Create a new reference
 pub(crate) fn create_reference(
     repo: &Repository,
@@ -176,6 +226,7 @@ pub(crate) fn create_reference(
                 name: r.inner.name.as_bstr().to_string(),
                 target: object_id.to_string(),
                 is_symbolic: false,
+                peeled_target: None,
             }),
             Err(err) => {
                 let msg = format!("Failed to create reference '{}': {}", name, err);
@@ -199,3 +250,266 @@ pub(crate) fn head(repo: &Repository) -> PyResult<String> {
             None => Err(repository_error("Repository HEAD is not set")),
         })
 }
+
+fn previous_value(expected_old: Option<&str>) -> PyResult<gix_ref::transaction::PreviousValue> {
+    match expected_old {
+        None => Ok(gix_ref::transaction::PreviousValue::Any),
+        Some(old) => {
+            let id = ObjectId::from_hex(old.as_bytes())
+                .map_err(|_| repository_error(format!("Invalid expected object ID: {}", old)))?;
+            Ok(gix_ref::transaction::PreviousValue::MustExistAndMatch(
+                gix_ref::Target::Object(id),
+            ))
+        }
+    }
+}
+
+/// Point a reference at a new target, creating the reference if it doesn't exist yet
+///
+/// When `expected_old` is given, the update only succeeds if the reference currently
+/// points at that object ID, giving callers a safe compare-and-swap.
+pub(crate) fn update_reference(
+    repo: &Repository,
+    name: &str,
+    new_target: &str,
+    expected_old: Option<&str>,
+) -> PyResult<GitReference> {
+    let full_name = name
+        .try_into()
+        .map_err(|_| repository_error(format!("Invalid reference name: {}", name)))?;
+    let object_id = ObjectId::from_hex(new_target.as_bytes())
+        .map_err(|_| repository_error(format!("Invalid object ID: {}", new_target)))?;
+    let expected = previous_value(expected_old)?;
+
+    let edit = gix_ref::transaction::RefEdit {
+        change: gix_ref::transaction::Change::Update {
+            log: gix_ref::transaction::LogChange {
+                mode: gix_ref::transaction::RefLog::AndReference,
+                force_create_reflog: false,
+                message: format!("update: {}", name).into(),
+            },
+            expected,
+            new: gix_ref::Target::Object(object_id),
+        },
+        name: full_name,
+        deref: false,
+    };
+
+    repo.inner
+        .edit_reference(edit)
+        .map_err(|err| repository_error(format!("Failed to update reference '{}': {}", name, err)))?;
+
+    find_reference(repo, name)
+}
+
+/// Delete a reference
+///
+/// When `expected_old` is given, the deletion only succeeds if the reference currently
+/// points at that object ID.
+pub(crate) fn delete_reference(repo: &Repository, name: &str, expected_old: Option<&str>) -> PyResult<()> {
+    let full_name = name
+        .try_into()
+        .map_err(|_| repository_error(format!("Invalid reference name: {}", name)))?;
+    let expected = previous_value(expected_old)?;
+
+    let edit = gix_ref::transaction::RefEdit {
+        change: gix_ref::transaction::Change::Delete {
+            expected,
+            log: gix_ref::transaction::RefLog::AndReference,
+        },
+        name: full_name,
+        deref: false,
+    };
+
+    repo.inner
+        .edit_reference(edit)
+        .map_err(|err| repository_error(format!("Failed to delete reference '{}': {}", name, err)))?;
+
+    Ok(())
+}
+
+/// A single create/update/delete operation to apply as part of a [`transaction`]
+///
+/// `action` is `"update"` (create or move `name` to `new_target`) or `"delete"`. `expected_old`,
+/// when given, makes the operation a compare-and-swap against the reference's current value.
+#[pyclass(unsendable)]
+#[derive(Clone)]
+pub struct RefEditSpec {
+    #[pyo3(get, set)]
+    pub action: String,
+    #[pyo3(get, set)]
+    pub name: String,
+    #[pyo3(get, set)]
+    pub new_target: Option<String>,
+    #[pyo3(get, set)]
+    pub expected_old: Option<String>,
+}
+
+#[pymethods]
+impl RefEditSpec {
+    #[new]
+    #[pyo3(signature = (action, name, new_target=None, expected_old=None))]
+    fn new(action: String, name: String, new_target: Option<String>, expected_old: Option<String>) -> Self {
+        Self {
+            action,
+            name,
+            new_target,
+            expected_old,
+        }
+    }
+}
+
+fn ref_edit_from_spec(spec: &RefEditSpec) -> PyResult<gix_ref::transaction::RefEdit> {
+    let full_name = spec
+        .name
+        .as_str()
+        .try_into()
+        .map_err(|_| repository_error(format!("Invalid reference name: {}", spec.name)))?;
+    let expected = previous_value(spec.expected_old.as_deref())?;
+
+    let change = match spec.action.as_str() {
+        "update" => {
+            let new_target = spec
+                .new_target
+                .as_deref()
+                .ok_or_else(|| repository_error(format!("'update' edit for '{}' is missing new_target", spec.name)))?;
+            let object_id = ObjectId::from_hex(new_target.as_bytes())
+                .map_err(|_| repository_error(format!("Invalid object ID: {}", new_target)))?;
+
+            gix_ref::transaction::Change::Update {
+                log: gix_ref::transaction::LogChange {
+                    mode: gix_ref::transaction::RefLog::AndReference,
+                    force_create_reflog: false,
+                    message: format!("transaction: update {}", spec.name).into(),
+                },
+                expected,
+                new: gix_ref::Target::Object(object_id),
+            }
+        }
+        "delete" => gix_ref::transaction::Change::Delete {
+            expected,
+            log: gix_ref::transaction::RefLog::AndReference,
+        },
+        other => return Err(repository_error(format!("Unknown ref edit action '{}'", other))),
+    };
+
+    Ok(gix_ref::transaction::RefEdit {
+        change,
+        name: full_name,
+        deref: false,
+    })
+}
+
+/// Apply a list of create/update/delete ref edits as a single atomic transaction
+///
+/// If any edit fails its compare-and-swap check (or targets an invalid reference name/object ID),
+/// none of the edits are applied.
+pub(crate) fn transaction(repo: &Repository, edits: Vec<RefEditSpec>) -> PyResult<()> {
+    let ref_edits = edits
+        .iter()
+        .map(ref_edit_from_spec)
+        .collect::<PyResult<Vec<_>>>()?;
+
+    repo.inner
+        .edit_references(ref_edits)
+        .map_err(|err| repository_error(format!("Failed to apply reference transaction: {}", err)))?;
+
+    Ok(())
+}
+
+/// Rename a reference, optionally overwriting an existing reference at the new name
+pub(crate) fn rename_reference(repo: &Repository, old: &str, new: &str, force: bool) -> PyResult<GitReference> {
+    let existing = find_reference(repo, old)?;
+
+    let target = if existing.is_symbolic {
+        let target_name = existing
+            .target
+            .as_str()
+            .try_into()
+            .map_err(|_| repository_error(format!("Invalid target reference name: {}", existing.target)))?;
+        gix_ref::Target::Symbolic(target_name)
+    } else {
+        let id = ObjectId::from_hex(existing.target.as_bytes())
+            .map_err(|_| repository_error(format!("Invalid object ID: {}", existing.target)))?;
+        gix_ref::Target::Object(id)
+    };
+
+    let new_name = new
+        .try_into()
+        .map_err(|_| repository_error(format!("Invalid reference name: {}", new)))?;
+    let create_constraint = if force {
+        gix_ref::transaction::PreviousValue::Any
+    } else {
+        gix_ref::transaction::PreviousValue::MustNotExist
+    };
+
+    let old_name = old
+        .try_into()
+        .map_err(|_| repository_error(format!("Invalid reference name: {}", old)))?;
+
+    let create_edit = gix_ref::transaction::RefEdit {
+        change: gix_ref::transaction::Change::Update {
+            log: gix_ref::transaction::LogChange {
+                mode: gix_ref::transaction::RefLog::AndReference,
+                force_create_reflog: false,
+                message: format!("rename: {} -> {}", old, new).into(),
+            },
+            expected: create_constraint,
+            new: target.clone(),
+        },
+        name: new_name,
+        deref: false,
+    };
+
+    let delete_edit = gix_ref::transaction::RefEdit {
+        change: gix_ref::transaction::Change::Delete {
+            expected: gix_ref::transaction::PreviousValue::MustExistAndMatch(target),
+            log: gix_ref::transaction::RefLog::AndReference,
+        },
+        name: old_name,
+        deref: false,
+    };
+
+    // Create the new ref and delete the old one as a single transaction, so a crash or a failed
+    // delete can never leave both names pointing at the same target, or drop the old ref without
+    // a replacement.
+    repo.inner
+        .edit_references(vec![create_edit, delete_edit])
+        .map_err(|err| repository_error(format!("Failed to rename '{}' to '{}': {}", old, new, err)))?;
+
+    find_reference(repo, new)
+}
+
+/// Retarget HEAD, either symbolically to a branch or as a detached object id
+pub(crate) fn set_head(repo: &Repository, target: &str, detached: bool) -> PyResult<GitReference> {
+    let new_target = if detached {
+        let id = ObjectId::from_hex(target.as_bytes())
+            .map_err(|_| repository_error(format!("Invalid object ID: {}", target)))?;
+        gix_ref::Target::Object(id)
+    } else {
+        let target_name = target
+            .try_into()
+            .map_err(|_| repository_error(format!("Invalid target reference name: {}", target)))?;
+        gix_ref::Target::Symbolic(target_name)
+    };
+
+    let edit = gix_ref::transaction::RefEdit {
+        change: gix_ref::transaction::Change::Update {
+            log: gix_ref::transaction::LogChange {
+                mode: gix_ref::transaction::RefLog::AndReference,
+                force_create_reflog: false,
+                message: format!("checkout: moving to {}", target).into(),
+            },
+            expected: gix_ref::transaction::PreviousValue::Any,
+            new: new_target,
+        },
+        name: "HEAD".try_into().expect("HEAD is a valid reference name"),
+        deref: false,
+    };
+
+    repo.inner
+        .edit_reference(edit)
+        .map_err(|err| repository_error(format!("Failed to set HEAD to '{}': {}", target, err)))?;
+
+    find_reference(repo, "HEAD")
+}
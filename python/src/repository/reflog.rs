@@ -0,0 +1,75 @@
+use pyo3::prelude::*;
+
+use crate::errors::reference_error;
+use crate::repository::core::Repository;
+
+/// A single entry in a reference's reflog
+#[pyclass(unsendable)]
+pub struct ReflogEntry {
+    #[pyo3(get)]
+    pub old_id: String,
+    #[pyo3(get)]
+    pub new_id: String,
+    #[pyo3(get)]
+    pub committer_name: String,
+    #[pyo3(get)]
+    pub committer_email: String,
+    #[pyo3(get)]
+    pub timestamp: i64,
+    #[pyo3(get)]
+    pub message: String,
+}
+
+/// Read the reflog for a reference, most-recent entry first
+///
+/// Args:
+///     limit: If given, return at most this many of the most-recent entries
+pub(crate) fn reflog(repo: &Repository, ref_name: &str, limit: Option<usize>) -> PyResult<Vec<ReflogEntry>> {
+    let reference = repo
+        .inner
+        .find_reference(ref_name)
+        .map_err(|err| reference_error(format!("Failed to find reference '{}': {}", ref_name, err)))?;
+
+    let mut entries = Vec::new();
+    reference
+        .log_iter()
+        .all()
+        .map_err(|err| reference_error(format!("Failed to read reflog for '{}': {}", ref_name, err)))?
+        .map(|lines| {
+            for line in lines {
+                let line =
+                    line.map_err(|err| reference_error(format!("Failed to read reflog line for '{}': {}", ref_name, err)))?;
+                entries.push(ReflogEntry {
+                    old_id: line.previous_oid().to_string(),
+                    new_id: line.new_oid().to_string(),
+                    committer_name: line.signature.name.to_string(),
+                    committer_email: line.signature.email.to_string(),
+                    timestamp: line.signature.time.seconds,
+                    message: line.message.to_string(),
+                });
+            }
+            Ok::<_, PyErr>(())
+        })
+        .transpose()?;
+
+    entries.reverse();
+    if let Some(limit) = limit {
+        entries.truncate(limit);
+    }
+
+    Ok(entries)
+}
+
+/// Check whether a reference has a reflog at all
+pub(crate) fn reflog_exists(repo: &Repository, ref_name: &str) -> PyResult<bool> {
+    let reference = repo
+        .inner
+        .find_reference(ref_name)
+        .map_err(|err| reference_error(format!("Failed to find reference '{}': {}", ref_name, err)))?;
+
+    Ok(reference
+        .log_iter()
+        .all()
+        .map_err(|err| reference_error(format!("Failed to read reflog for '{}': {}", ref_name, err)))?
+        .is_some())
+}
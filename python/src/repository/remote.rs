@@ -0,0 +1,184 @@
+use gix_hash::ObjectId;
+use pyo3::prelude::*;
+use std::collections::HashSet;
+
+use crate::errors::{remote_error, repository_error};
+use crate::repository::core::{GitReference, Repository};
+
+/// A configured remote, as in `.git/config`'s `[remote "name"]` sections
+#[pyclass(unsendable)]
+pub struct GitRemote {
+    #[pyo3(get)]
+    pub name: String,
+    #[pyo3(get)]
+    pub url: Option<String>,
+    #[pyo3(get)]
+    pub push_url: Option<String>,
+}
+
+/// The outcome of a `fetch()` call: the local reference names the fetch's refspecs mapped to,
+/// including references whose target did not change
+#[pyclass(unsendable)]
+pub struct FetchOutcome {
+    #[pyo3(get)]
+    pub updated_refs: Vec<String>,
+}
+
+/// Get the names of all configured remotes
+pub(crate) fn remote_names(repo: &Repository) -> PyResult<Vec<String>> {
+    Ok(repo
+        .inner
+        .remote_names()
+        .into_iter()
+        .map(|name| name.to_string())
+        .collect())
+}
+
+/// Find a configured remote by name
+pub(crate) fn find_remote(repo: &Repository, name: &str) -> PyResult<GitRemote> {
+    let remote = repo
+        .inner
+        .find_remote(name)
+        .map_err(|err| remote_error(format!("Failed to find remote '{}': {}", name, err)))?;
+
+    let url = remote
+        .url(gix::remote::Direction::Fetch)
+        .map(|url| url.to_bstring().to_string());
+    let push_url = remote
+        .url(gix::remote::Direction::Push)
+        .map(|url| url.to_bstring().to_string());
+
+    Ok(GitRemote {
+        name: name.to_string(),
+        url,
+        push_url,
+    })
+}
+
+/// Fetch from a named remote, optionally restricting the refspecs used
+pub(crate) fn fetch(repo: &Repository, remote_name: &str, refspecs: Option<Vec<String>>) -> PyResult<FetchOutcome> {
+    let mut remote = repo
+        .inner
+        .find_remote(remote_name)
+        .map_err(|err| remote_error(format!("Failed to find remote '{}': {}", remote_name, err)))?;
+
+    if let Some(refspecs) = refspecs {
+        remote = remote
+            .with_refspecs(refspecs.iter().map(String::as_str), gix::remote::Direction::Fetch)
+            .map_err(|err| remote_error(format!("Invalid refspec for remote '{}': {}", remote_name, err)))?;
+    }
+
+    let connection = remote
+        .connect(gix::remote::Direction::Fetch)
+        .map_err(|err| remote_error(format!("Failed to connect to remote '{}': {}", remote_name, err)))?;
+
+    let outcome = connection
+        .prepare_fetch(gix::progress::Discard, gix::remote::ref_map::Options::default())
+        .map_err(|err| remote_error(format!("Failed to prepare fetch from '{}': {}", remote_name, err)))?
+        .receive(gix::progress::Discard, &std::sync::atomic::AtomicBool::new(false))
+        .map_err(|err| remote_error(format!("Failed to fetch from '{}': {}", remote_name, err)))?;
+
+    let updated_refs = outcome
+        .ref_map
+        .mappings
+        .iter()
+        .filter_map(|mapping| mapping.local.as_ref())
+        .map(|name| name.to_string())
+        .collect();
+
+    Ok(FetchOutcome { updated_refs })
+}
+
+/// Classify the relationship between a local reference and an incoming commit
+///
+/// Returns a set containing one of `"up_to_date"`, `"fast_forward"`, `"normal"` or `"unrelated"`,
+/// mirroring pygit2's `MergeAnalysis` flag set. `"up_to_date"` means `incoming_commit` is an
+/// ancestor of (or equal to) the local reference; `"fast_forward"` means the local reference is
+/// an ancestor of `incoming_commit`.
+pub(crate) fn merge_analysis(repo: &Repository, local_ref: &str, incoming_commit: &str) -> PyResult<HashSet<String>> {
+    let incoming_id = ObjectId::from_hex(incoming_commit.as_bytes())
+        .map_err(|_| repository_error(format!("Invalid object ID: {}", incoming_commit)))?;
+
+    let local_id = match repo.inner.find_reference(local_ref) {
+        Ok(mut reference) => reference
+            .peel_to_id_in_place()
+            .map_err(|err| repository_error(format!("Failed to peel reference '{}': {}", local_ref, err)))?
+            .detach(),
+        Err(_) => return Ok(HashSet::from(["fast_forward".to_string()])),
+    };
+
+    if local_id == incoming_id {
+        return Ok(HashSet::from(["up_to_date".to_string()]));
+    }
+
+    let flag = match repo.inner.merge_base(local_id, incoming_id) {
+        Ok(base) if base == incoming_id => "up_to_date",
+        Ok(base) if base == local_id => "fast_forward",
+        Ok(_) => "normal",
+        Err(_) => "unrelated",
+    };
+
+    Ok(HashSet::from([flag.to_string()]))
+}
+
+/// Fast-forward `local_ref` to `incoming_commit`, but only if `merge_analysis` reports `"fast_forward"`
+///
+/// This only moves `local_ref`'s target; it does not touch the working tree or index. That's safe
+/// for any ref that isn't currently checked out (e.g. a bare repo, or a branch other than the one
+/// HEAD points to), but fast-forwarding the checked-out branch this way would leave the worktree
+/// and index silently out of sync with the new tree, so that case is rejected rather than risking
+/// a caller mistaking a ref-only move for a full working-tree update.
+pub(crate) fn fast_forward(repo: &Repository, local_ref: &str, incoming_commit: &str) -> PyResult<GitReference> {
+    let analysis = merge_analysis(repo, local_ref, incoming_commit)?;
+    if !analysis.contains("fast_forward") {
+        return Err(repository_error(format!(
+            "Cannot fast-forward '{}': relationship to '{}' is {:?}",
+            local_ref, incoming_commit, analysis
+        )));
+    }
+
+    if !repo.inner.is_bare() {
+        if let Ok(Some(head)) = repo.inner.head_ref() {
+            if head.inner.name.as_bstr().to_string() == local_ref {
+                return Err(repository_error(format!(
+                    "Cannot fast-forward '{}': it is the checked-out branch, and fast_forward only \
+                     moves the reference, it does not update the working tree or index; check out the \
+                     new commit instead",
+                    local_ref
+                )));
+            }
+        }
+    }
+
+    let incoming_id = ObjectId::from_hex(incoming_commit.as_bytes())
+        .map_err(|_| repository_error(format!("Invalid object ID: {}", incoming_commit)))?;
+
+    let full_name = local_ref
+        .try_into()
+        .map_err(|_| repository_error(format!("Invalid reference name: {}", local_ref)))?;
+
+    let expected = match repo.inner.find_reference(local_ref) {
+        Ok(reference) => gix_ref::transaction::PreviousValue::MustExistAndMatch(reference.inner.target.clone()),
+        Err(_) => gix_ref::transaction::PreviousValue::MustNotExist,
+    };
+
+    let edit = gix_ref::transaction::RefEdit {
+        change: gix_ref::transaction::Change::Update {
+            log: gix_ref::transaction::LogChange {
+                mode: gix_ref::transaction::RefLog::AndReference,
+                force_create_reflog: false,
+                message: "fast-forward".into(),
+            },
+            expected,
+            new: gix_ref::Target::Object(incoming_id),
+        },
+        name: full_name,
+        deref: false,
+    };
+
+    repo.inner
+        .edit_reference(edit)
+        .map_err(|err| repository_error(format!("Failed to fast-forward '{}': {}", local_ref, err)))?;
+
+    crate::repository::references::find_reference(repo, local_ref)
+}
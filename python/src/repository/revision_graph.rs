@@ -0,0 +1,102 @@
+use gix_hash::ObjectId;
+use pyo3::prelude::*;
+
+use crate::errors::repository_error;
+use crate::repository::core::Repository;
+
+fn parse_object_id(id: &str) -> PyResult<ObjectId> {
+    ObjectId::from_hex(id.as_bytes()).map_err(|_| repository_error(format!("Invalid object ID: {}", id)))
+}
+
+/// A reusable handle onto a repository's commit-graph cache
+///
+/// `merge_base`/`merge_bases`/`merge_base_octopus` on [`Repository`] each call
+/// `commit_graph_if_enabled()` and build a fresh revision graph per call, which is wasteful for an
+/// application answering many such queries (e.g. computing merge bases across a batch of branch
+/// pairs). Create one `RevisionGraph` via `Repository.revision_graph()` up front and reuse it
+/// across queries instead; the commit-graph cache is loaded once, at construction time.
+#[pyclass(unsendable)]
+pub struct RevisionGraph {
+    repo: gix::Repository,
+    cache: Option<gix::commitgraph::Graph>,
+}
+
+impl RevisionGraph {
+    pub(crate) fn new(repo: &Repository) -> PyResult<Self> {
+        let cache = repo
+            .inner
+            .commit_graph_if_enabled()
+            .map_err(|err| repository_error(format!("Failed to load commit-graph: {}", err)))?;
+        Ok(Self {
+            repo: repo.inner.clone(),
+            cache,
+        })
+    }
+}
+
+impl RevisionGraph {
+    /// Find all merge bases between `one` and `others`, over the shared, cache-backed graph
+    fn merge_bases_many_ids(&self, one: ObjectId, others: &[ObjectId]) -> PyResult<Vec<ObjectId>> {
+        let mut graph = self.repo.revision_graph(self.cache.as_ref());
+        self.repo
+            .merge_bases_many_with_graph(one, others, &mut graph)
+            .map_err(|err| repository_error(format!("Failed to find merge bases: {}", err)))
+    }
+}
+
+#[pymethods]
+impl RevisionGraph {
+    /// Find the best merge base between two commits, reusing the cached commit-graph
+    fn merge_base(&self, one: &str, two: &str) -> PyResult<String> {
+        let first_id = parse_object_id(one)?;
+        let second_id = parse_object_id(two)?;
+
+        let bases = self.merge_bases_many_ids(first_id, &[second_id])?;
+        bases
+            .into_iter()
+            .next()
+            .map(|id| id.to_string())
+            .ok_or_else(|| repository_error(format!("No merge base between '{}' and '{}'", one, two)))
+    }
+
+    /// Find all merge bases between one commit and multiple other commits, reusing the cached
+    /// commit-graph across calls
+    fn merge_bases_many(&self, one: &str, others: Vec<String>) -> PyResult<Vec<String>> {
+        let first_id = parse_object_id(one)?;
+        let other_ids = others.iter().map(|id| parse_object_id(id)).collect::<PyResult<Vec<_>>>()?;
+
+        Ok(self
+            .merge_bases_many_ids(first_id, &other_ids)?
+            .iter()
+            .map(|id| id.to_string())
+            .collect())
+    }
+
+    /// Find the best merge base among multiple commits, reusing the cached commit-graph
+    ///
+    /// Folds pairwise over `commits` via [`merge_bases_many_ids`][Self::merge_bases_many_ids]:
+    /// the running merge base is paired against each subsequent commit in turn, taking the first
+    /// (best) result each time, over the same shared graph.
+    fn merge_base_octopus(&self, commits: Vec<String>) -> PyResult<String> {
+        if commits.is_empty() {
+            return Err(repository_error("No commits provided for merge_base_octopus"));
+        }
+        let mut ids = commits.iter().map(|id| parse_object_id(id)).collect::<PyResult<Vec<_>>>()?.into_iter();
+        let mut base = ids.next().expect("checked non-empty above");
+
+        for next in ids {
+            base = self
+                .merge_bases_many_ids(base, &[next])?
+                .into_iter()
+                .next()
+                .ok_or_else(|| repository_error("No merge base octopus exists for the given commits"))?;
+        }
+
+        Ok(base.to_string())
+    }
+}
+
+/// Create a [`RevisionGraph`] for `repo`, loading its commit-graph cache once
+pub(crate) fn revision_graph(repo: &Repository) -> PyResult<RevisionGraph> {
+    RevisionGraph::new(repo)
+}
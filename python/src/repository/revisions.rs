@@ -4,87 +4,229 @@ use pyo3::prelude::*;
 use crate::errors::repository_error;
 use crate::repository::core::Repository;
 
-/// Find all merge bases between one commit and multiple other commits
-pub(crate) fn merge_bases(repo: &Repository, one: &str, others: Vec<String>) -> PyResult<Vec<String>> {
-    // Parse the first commit ID
-    let first_id = ObjectId::from_hex(one.as_bytes())
-        .map_err(|_| repository_error(format!("Invalid object ID for first commit: {}", one)))?;
-
-    // Parse the other commit IDs
-    let mut other_ids = Vec::with_capacity(others.len());
-    for (idx, other) in others.iter().enumerate() {
-        let id = ObjectId::from_hex(other.as_bytes())
-            .map_err(|_| repository_error(format!("Invalid object ID for other commit {}: {}", idx, other)))?;
-        other_ids.push(id);
+/// A Python iterator over ancestor commit IDs produced by [`walk`]
+///
+/// The full traversal is resolved eagerly when the walk is created (so an invalid tip raises
+/// `RepositoryError` immediately), and `__next__` simply yields the resolved IDs one at a time.
+#[pyclass(unsendable)]
+pub struct CommitWalk {
+    ids: Vec<String>,
+    index: usize,
+}
+
+#[pymethods]
+impl CommitWalk {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
     }
 
-    // Get the commit graph
-    let cache = repo
-        .inner
-        .commit_graph_if_enabled()
-        .map_err(|err| repository_error(format!("Failed to retrieve commit graph: {}", err)))?;
-    let mut graph = repo.inner.revision_graph(cache.as_ref());
+    fn __next__(&mut self) -> Option<String> {
+        let id = self.ids.get(self.index).cloned();
+        self.index += 1;
+        id
+    }
+}
 
-    // Find the merge bases
-    repo.inner
-        .merge_bases_many_with_graph(first_id, &other_ids, &mut graph)
-        .map_err(|err| repository_error(format!("Failed to find merge bases: {}", err)))
-        .map(|bases| bases.iter().map(|id| id.to_string()).collect())
+fn parse_ids(ids: &[String], role: &str) -> PyResult<Vec<ObjectId>> {
+    ids.iter()
+        .map(|id| {
+            ObjectId::from_hex(id.as_bytes())
+                .map_err(|_| repository_error(format!("Invalid {} commit ID: {}", role, id)))
+        })
+        .collect()
+}
+
+/// Walk ancestor commits starting from `tips`, hiding the ancestors of `hide`
+///
+/// Mirrors git2's `Revwalk`: `sort` is one of `"topo"`, `"date"`, or `"reverse"`. The walk is
+/// resolved eagerly so invalid tip/hide IDs raise `RepositoryError` before any commit is yielded.
+pub(crate) fn walk(repo: &Repository, tips: Vec<String>, hide: Vec<String>, sort: &str) -> PyResult<CommitWalk> {
+    let tip_ids = parse_ids(&tips, "tip")?;
+    let hide_ids = parse_ids(&hide, "hide")?;
+
+    let sorting = match sort {
+        "topo" => gix::revision::walk::Sorting::TopoOrder,
+        "date" | "reverse" => gix::revision::walk::Sorting::ByCommitTimeNewestFirst,
+        other => return Err(repository_error(format!("Unknown sort mode '{}', expected topo/date/reverse", other))),
+    };
+
+    let mut walk = repo.inner.rev_walk(tip_ids).sorting(sorting);
+    if !hide_ids.is_empty() {
+        walk = walk.with_hidden(hide_ids);
+    }
+
+    let mut ids = walk
+        .all()
+        .map_err(|err| repository_error(format!("Failed to start history walk: {}", err)))?
+        .map(|info| {
+            info.map(|info| info.id.to_string())
+                .map_err(|err| repository_error(format!("Failed to walk history: {}", err)))
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+
+    if sort == "reverse" {
+        ids.reverse();
+    }
+
+    Ok(CommitWalk { ids, index: 0 })
+}
+
+/// Find all merge bases between one commit and multiple other commits
+///
+/// Delegates to a per-call [`RevisionGraph`][crate::repository::RevisionGraph]; applications
+/// answering many such queries should build one graph via `Repository.revision_graph()` and
+/// reuse it instead, to avoid reloading the commit-graph cache on every call.
+pub(crate) fn merge_bases(repo: &Repository, one: &str, others: Vec<String>) -> PyResult<Vec<String>> {
+    crate::repository::revision_graph::RevisionGraph::new(repo)?.merge_bases_many(one, others)
 }
 
 /// Find the best merge base between two commits
+///
+/// Delegates to a per-call [`RevisionGraph`][crate::repository::RevisionGraph]; see its
+/// documentation for reusing the commit-graph cache across many queries.
 pub(crate) fn merge_base(repo: &Repository, one: &str, two: &str) -> PyResult<String> {
-    // Parse the commit IDs
-    let first_id = ObjectId::from_hex(one.as_bytes())
-        .map_err(|_| repository_error(format!("Invalid object ID for first commit: {}", one)))?;
+    crate::repository::revision_graph::RevisionGraph::new(repo)?.merge_base(one, two)
+}
 
-    let second_id = ObjectId::from_hex(two.as_bytes())
-        .map_err(|_| repository_error(format!("Invalid object ID for second commit: {}", two)))?;
+/// Parse a revision specification and return a single commit/object ID
+///
+/// Raises a clear error pointing to [`rev_parse_range`] if `spec` is a two-endpoint range.
+pub(crate) fn rev_parse(repo: &Repository, spec: &str) -> PyResult<String> {
+    if spec.contains("..") {
+        return Err(repository_error(format!(
+            "'{}' is a revision range, not a single object; use rev_parse_range() instead",
+            spec
+        )));
+    }
 
-    // Find the merge base
     repo.inner
-        .merge_base(first_id, second_id)
-        .map_err(|err| repository_error(format!("Failed to find merge base: {}", err)))
+        .rev_parse_single(spec)
+        .map_err(|err| repository_error(format!("Failed to parse revision '{}': {}", spec, err)))
         .map(|id| id.to_string())
 }
 
-/// Parse a revision specification and return a single commit/object ID
-pub(crate) fn rev_parse(repo: &Repository, spec: &str) -> PyResult<String> {
+/// A parsed revision range, ready to be fed into [`walk`][crate::repository::CommitWalk]-style
+/// history traversal as `tips`/`hide`
+#[pyclass(unsendable)]
+#[derive(Clone)]
+pub struct RevSpecRange {
+    /// One of `"single"`, `"range"` (`A..B`), `"symmetric_difference"` (`A...B`),
+    /// `"parents_only"` (`A^@`), or `"exclusive"` (`A^!`)
+    #[pyo3(get)]
+    pub kind: String,
+    #[pyo3(get)]
+    pub from: Option<String>,
+    #[pyo3(get)]
+    pub to: Option<String>,
+    /// Commit IDs to start a history walk from
+    #[pyo3(get)]
+    pub tips: Vec<String>,
+    /// Commit IDs whose ancestors (and themselves) should be excluded from the walk
+    #[pyo3(get)]
+    pub hide: Vec<String>,
+}
+
+fn resolve_endpoint(repo: &Repository, spec: &str, default_if_empty: &str) -> PyResult<String> {
+    let spec = if spec.is_empty() { default_if_empty } else { spec };
     repo.inner
         .rev_parse_single(spec)
-        .map_err(|err| repository_error(format!("Failed to parse revision '{}': {}", spec, err)))
+        .map_err(|err| repository_error(format!("Failed to resolve revision '{}': {}", spec, err)))
         .map(|id| id.to_string())
 }
 
-/// Find the best merge base among multiple commits
-pub(crate) fn merge_base_octopus(repo: &Repository, commits: Vec<String>) -> PyResult<String> {
-    // Check if we have at least one commit
-    if commits.is_empty() {
-        return Err(repository_error(
-            "No commits provided for merge_base_octopus".to_string(),
-        ));
+fn parent_ids_of(repo: &Repository, id: &str) -> PyResult<Vec<String>> {
+    let object_id =
+        ObjectId::from_hex(id.as_bytes()).map_err(|_| repository_error(format!("Invalid object ID: {}", id)))?;
+    let commit = repo
+        .inner
+        .find_commit(object_id)
+        .map_err(|err| repository_error(format!("Failed to find commit '{}': {}", id, err)))?;
+    Ok(commit.parent_ids().map(|id| id.to_string()).collect())
+}
+
+/// Parse a revision range specification, e.g. `"main..feature"`, `"A...B"`, `"HEAD^@"`, `"HEAD^!"`
+///
+/// Args:
+///     spec: The revision range specification
+///
+/// Returns:
+///     A RevSpecRange describing the parsed endpoints and the `tips`/`hide` sets they expand to
+///
+/// Raises:
+///     RepositoryError: If either endpoint fails to resolve
+pub(crate) fn rev_parse_range(repo: &Repository, spec: &str) -> PyResult<RevSpecRange> {
+    let spec = spec.trim();
+
+    if let Some((left, right)) = spec.split_once("...") {
+        let from = resolve_endpoint(repo, left, "HEAD")?;
+        let to = resolve_endpoint(repo, right, "HEAD")?;
+        let from_id = ObjectId::from_hex(from.as_bytes()).expect("resolve_endpoint returns valid hex");
+        let to_id = ObjectId::from_hex(to.as_bytes()).expect("resolve_endpoint returns valid hex");
+        let hide = repo
+            .inner
+            .merge_base(from_id, to_id)
+            .map(|base| vec![base.to_string()])
+            .unwrap_or_default();
+
+        return Ok(RevSpecRange {
+            kind: "symmetric_difference".to_string(),
+            from: Some(from.clone()),
+            to: Some(to.clone()),
+            tips: vec![from, to],
+            hide,
+        });
     }
 
-    // Convert string IDs to ObjectIds
-    let commit_ids: Result<Vec<_>, _> = commits
-        .iter()
-        .map(|id_str| {
-            ObjectId::from_hex(id_str.as_bytes())
-                .map_err(|_| repository_error(format!("Invalid object ID: {}", id_str)))
-        })
-        .collect();
+    if let Some((left, right)) = spec.split_once("..") {
+        let from = resolve_endpoint(repo, left, "HEAD")?;
+        let to = resolve_endpoint(repo, right, "HEAD")?;
+        return Ok(RevSpecRange {
+            kind: "range".to_string(),
+            from: Some(from.clone()),
+            to: Some(to.clone()),
+            tips: vec![to],
+            hide: vec![from],
+        });
+    }
 
-    let commit_ids = commit_ids?;
+    if let Some(base) = spec.strip_suffix("^@") {
+        let base_id = resolve_endpoint(repo, base, "HEAD")?;
+        let parents = parent_ids_of(repo, &base_id)?;
+        return Ok(RevSpecRange {
+            kind: "parents_only".to_string(),
+            from: Some(base_id),
+            to: None,
+            tips: parents,
+            hide: Vec::new(),
+        });
+    }
 
-    // Get the commit graph
-    let _cache = repo
-        .inner
-        .commit_graph_if_enabled()
-        .map_err(|err| repository_error(format!("Failed to retrieve commit graph: {}", err)))?;
+    if let Some(base) = spec.strip_suffix("^!") {
+        let base_id = resolve_endpoint(repo, base, "HEAD")?;
+        let parents = parent_ids_of(repo, &base_id)?;
+        return Ok(RevSpecRange {
+            kind: "exclusive".to_string(),
+            from: None,
+            to: Some(base_id.clone()),
+            tips: vec![base_id],
+            hide: parents,
+        });
+    }
 
-    // Find the merge base
-    repo.inner
-        .merge_base_octopus(commit_ids)
-        .map_err(|err| repository_error(format!("Failed to find merge base octopus: {}", err)))
-        .map(|id| id.to_string())
+    let id = resolve_endpoint(repo, spec, "HEAD")?;
+    Ok(RevSpecRange {
+        kind: "single".to_string(),
+        from: None,
+        to: Some(id.clone()),
+        tips: vec![id],
+        hide: Vec::new(),
+    })
+}
+
+/// Find the best merge base among multiple commits
+///
+/// Delegates to a per-call [`RevisionGraph`][crate::repository::RevisionGraph]; see its
+/// documentation for reusing the commit-graph cache across many queries.
+pub(crate) fn merge_base_octopus(repo: &Repository, commits: Vec<String>) -> PyResult<String> {
+    crate::repository::revision_graph::RevisionGraph::new(repo)?.merge_base_octopus(commits)
 }
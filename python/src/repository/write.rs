@@ -0,0 +1,229 @@
+use gix_hash::ObjectId;
+use pyo3::prelude::*;
+
+use crate::errors::{object_error, reference_error, repository_error};
+use crate::repository::core::Repository;
+
+/// Incrementally builds a tree object from `(name, id, mode)` entries
+///
+/// Obtained via [`Repository.tree_builder`][crate::repository::Repository::tree_builder]; call
+/// `insert()` for each entry and `write()` once to persist the tree and get its hex ID.
+#[pyclass(unsendable)]
+pub struct TreeBuilder {
+    repo: gix::Repository,
+    entries: Vec<gix::objs::tree::Entry>,
+}
+
+#[pymethods]
+impl TreeBuilder {
+    /// Insert (or replace) an entry in the tree under construction
+    ///
+    /// Args:
+    ///     name: The entry's file name within this tree
+    ///     id: The hex object ID of the blob/tree/commit the entry points to
+    ///     mode: The octal file mode, e.g. 0o100644 for a regular file or 0o040000 for a subtree
+    fn insert(&mut self, name: &str, id: &str, mode: u32) -> PyResult<()> {
+        let oid =
+            ObjectId::from_hex(id.as_bytes()).map_err(|_| repository_error(format!("Invalid object ID: {}", id)))?;
+        let mode = gix::objs::tree::EntryMode::try_from(mode)
+            .map_err(|_| repository_error(format!("Invalid tree entry mode: {:#o}", mode)))?;
+
+        self.entries.retain(|entry| entry.filename != name);
+        self.entries.push(gix::objs::tree::Entry {
+            mode,
+            filename: name.into(),
+            oid,
+        });
+        self.entries.sort();
+
+        Ok(())
+    }
+
+    /// Write the accumulated entries as a tree object, returning its hex ID
+    fn write(&self) -> PyResult<String> {
+        let tree = gix::objs::Tree {
+            entries: self.entries.clone(),
+        };
+
+        self.repo
+            .write_object(&tree)
+            .map_err(|err| object_error(format!("Failed to write tree: {}", err)))
+            .map(|id| id.to_string())
+    }
+}
+
+/// Create a tree builder for incrementally constructing a new tree object
+pub(crate) fn tree_builder(repo: &Repository) -> TreeBuilder {
+    TreeBuilder {
+        repo: repo.inner.clone(),
+        entries: Vec::new(),
+    }
+}
+
+/// Write `data` as a new blob object, returning its hex ID
+pub(crate) fn write_blob(repo: &Repository, data: &[u8]) -> PyResult<String> {
+    repo.inner
+        .write_blob(data)
+        .map_err(|err| object_error(format!("Failed to write blob: {}", err)))
+        .map(|id| id.to_string())
+}
+
+/// Create an annotated tag object pointing at `target`, and a `refs/tags/<name>` reference to it
+///
+/// Args:
+///     name: The tag's name, without the `refs/tags/` prefix
+///     target: The object ID the tag points to
+///     tagger: An (name, email, time) tuple, or None to use the repository's configured signature
+///     message: The tag message
+///     force: If True, overwrite an existing tag reference with the same name
+///
+/// Returns:
+///     The new tag object's ID
+pub(crate) fn create_tag(
+    repo: &Repository,
+    name: &str,
+    target: &str,
+    tagger: Option<SignatureTuple>,
+    message: &str,
+    force: bool,
+) -> PyResult<String> {
+    let target_id = ObjectId::from_hex(target.as_bytes())
+        .map_err(|_| repository_error(format!("Invalid target object ID: {}", target)))?;
+
+    let target_kind = repo
+        .inner
+        .find_object(target_id)
+        .map_err(|err| object_error(format!("Failed to find tag target '{}': {}", target, err)))?
+        .kind;
+
+    let tagger = signature_from_tuple(tagger, repo)?;
+
+    let tag = gix::objs::Tag {
+        target: target_id,
+        target_kind,
+        name: name.into(),
+        tagger: Some(tagger),
+        message: message.into(),
+        pgp_signature: None,
+    };
+
+    let tag_id = repo
+        .inner
+        .write_object(&tag)
+        .map_err(|err| object_error(format!("Failed to write tag object: {}", err)))?;
+
+    let previous = if force {
+        gix::refs::transaction::PreviousValue::Any
+    } else {
+        gix::refs::transaction::PreviousValue::MustNotExist
+    };
+
+    repo.inner
+        .reference(
+            format!("refs/tags/{}", name),
+            tag_id,
+            previous,
+            format!("tag: tagging {} as '{}'", target, name),
+        )
+        .map_err(|err| reference_error(format!("Failed to create tag ref 'refs/tags/{}': {}", name, err)))?;
+
+    Ok(tag_id.to_string())
+}
+
+/// An `(name, email, time)` tuple describing a commit signature
+pub(crate) type SignatureTuple = (String, String, i64);
+
+fn signature_from_tuple(sig: Option<SignatureTuple>, repo: &Repository) -> PyResult<gix_actor::Signature> {
+    match sig {
+        Some((name, email, time)) => Ok(gix_actor::Signature {
+            name: name.into(),
+            email: email.into(),
+            time: gix_date::Time::new(time, 0),
+        }),
+        None => repo
+            .inner
+            .committer()
+            .transpose()
+            .map_err(|err| repository_error(format!("Failed to read configured signature: {}", err)))?
+            .ok_or_else(|| repository_error("No author/committer given and none configured for this repository"))
+            .map(|sig| sig.into()),
+    }
+}
+
+/// Write the currently staged index to a tree object, returning its ID
+pub(crate) fn write_tree_from_index(repo: &Repository) -> PyResult<String> {
+    let index = repo
+        .inner
+        .index_or_empty()
+        .map_err(|err| repository_error(format!("Failed to read index: {}", err)))?;
+
+    let tree_id = repo
+        .inner
+        .write_index_as_tree(&index)
+        .map_err(|err| object_error(format!("Failed to write tree from index: {}", err)))?;
+
+    Ok(tree_id.to_string())
+}
+
+/// Create a new commit, optionally updating a reference to point at it
+///
+/// Args:
+///     update_ref: The reference to update to the new commit (e.g., "HEAD"), or None to not update any ref
+///     author: An (name, email, time) tuple, or None to use the repository's configured signature
+///     committer: An (name, email, time) tuple, or None to use the repository's configured signature
+///     message: The commit message
+///     tree_id: The tree object ID this commit points to
+///     parents: The parent commit IDs
+///
+/// Returns:
+///     The new commit's object ID
+pub(crate) fn create_commit(
+    repo: &Repository,
+    update_ref: Option<&str>,
+    author: Option<SignatureTuple>,
+    committer: Option<SignatureTuple>,
+    message: &str,
+    tree_id: &str,
+    parents: Vec<String>,
+) -> PyResult<String> {
+    let tree = ObjectId::from_hex(tree_id.as_bytes())
+        .map_err(|_| repository_error(format!("Invalid tree object ID: {}", tree_id)))?;
+
+    let parent_ids: Result<Vec<ObjectId>, PyErr> = parents
+        .iter()
+        .map(|p| {
+            ObjectId::from_hex(p.as_bytes()).map_err(|_| repository_error(format!("Invalid parent object ID: {}", p)))
+        })
+        .collect();
+    let parent_ids = parent_ids?;
+
+    let author = signature_from_tuple(author, repo)?;
+    let committer = signature_from_tuple(committer, repo)?;
+
+    let commit_id = match update_ref {
+        Some(reference) => repo
+            .inner
+            .commit_as(committer, author, reference, message, tree, parent_ids)
+            .map_err(|err| object_error(format!("Failed to create commit: {}", err)))?,
+        None => {
+            // Write the commit object directly rather than going through `commit_as`, which
+            // always moves a reference; a caller passing `update_ref=None` wants a detached
+            // commit object with no ref (including HEAD) touched.
+            let commit = gix::objs::Commit {
+                tree,
+                parents: parent_ids.into(),
+                author,
+                committer,
+                encoding: None,
+                message: message.into(),
+                extra_headers: Vec::new(),
+            };
+
+            repo.inner
+                .write_object(&commit)
+                .map_err(|err| object_error(format!("Failed to create commit: {}", err)))?
+        }
+    };
+
+    Ok(commit_id.to_string())
+}